@@ -0,0 +1,239 @@
+use anchor_lang::prelude::*;
+use crate::error::SSSOneError;
+use crate::events::*;
+use crate::instructions::AuthorityManagement;
+use crate::state::*;
+
+impl AdminAction {
+    /// Apply the action to the config on behalf of `actor`, either the
+    /// authority key or the multisig executing a proposal
+    pub fn apply(&self, config: &mut StablecoinConfig, actor: Pubkey) -> Result<()> {
+        match self {
+            AdminAction::ProposeAuthority { new_authority } => {
+                config.pending_authority = Some(*new_authority);
+                emit!(AuthorityTransferProposed {
+                    authority: actor,
+                    pending_authority: *new_authority,
+                });
+                msg!("Authority transfer proposed to {}", new_authority);
+            }
+            AdminAction::CancelAuthorityTransfer => {
+                let pending_authority = config.pending_authority.take()
+                    .ok_or(SSSOneError::NoPendingTransfer)?;
+                emit!(AuthorityTransferCancelled { pending_authority });
+            }
+            AdminAction::ProposeRole { role, new_holder } => {
+                *config.pending_role_mut(*role) = Some(*new_holder);
+                emit!(RoleTransferProposed {
+                    role: *role,
+                    pending_holder: *new_holder,
+                });
+            }
+            AdminAction::CancelRoleTransfer { role } => {
+                let pending_holder = config.pending_role_mut(*role).take()
+                    .ok_or(SSSOneError::NoPendingTransfer)?;
+                emit!(RoleTransferCancelled {
+                    role: *role,
+                    pending_holder,
+                });
+            }
+            AdminAction::SetMintRateLimit { max_amount, window_seconds } => {
+                config.mint_rate_limit = max_amount
+                    .map(|max_amount| RateLimit::new(max_amount, *window_seconds))
+                    .transpose()?;
+                emit!(MintRateLimitUpdated {
+                    minter: None,
+                    max_amount: *max_amount,
+                    window_seconds: *window_seconds,
+                });
+            }
+            AdminAction::UnpauseOperations { mask } => {
+                let flags = config.unpause_operations(*mask)?;
+                emit!(OperationsUnpaused { flags, authority: actor });
+                msg!("Operations {:#010b} unpaused by {}", flags, actor);
+            }
+            AdminAction::SetTimelockDelay { delay } => {
+                if let Some(delay) = delay {
+                    require!(*delay > 0, SSSOneError::InvalidTimelockDelay);
+                }
+                config.timelock_delay = *delay;
+                emit!(TimelockDelayUpdated { delay: *delay });
+            }
+            AdminAction::SetSupplyCap { max_supply } => {
+                let previous_cap = config.max_supply;
+                config.max_supply = *max_supply;
+                emit!(SupplyCapUpdated {
+                    previous_cap,
+                    new_cap: *max_supply,
+                });
+            }
+            AdminAction::SetPauseOnSupplyDiscrepancy { enabled } => {
+                config.pause_on_supply_discrepancy = *enabled;
+                emit!(PauseOnSupplyDiscrepancyUpdated { enabled: *enabled });
+            }
+            AdminAction::SetRedeemRequiresKyc { enabled } => {
+                config.redeem_requires_kyc = *enabled;
+                emit!(RedeemRequiresKycUpdated { enabled: *enabled });
+            }
+            AdminAction::SetReservePolicy { policy } => {
+                if let Some(policy) = policy {
+                    policy.validate()?;
+                }
+                let previous_policy = config.reserve_policy;
+                config.reserve_policy = *policy;
+                emit!(ReservePolicyUpdated {
+                    previous_policy,
+                    new_policy: *policy,
+                });
+            }
+            AdminAction::UpdateMultisig { .. } => {
+                // Only reachable through `execute`, which updates the multisig
+                return err!(SSSOneError::MultisigRequired);
+            }
+            AdminAction::SetMinterRateLimit { .. }
+            | AdminAction::ApproveConfidentialAccount { .. }
+            | AdminAction::RotateAuditorKey { .. }
+            | AdminAction::Seize { .. }
+            | AdminAction::SyncSupply { .. } => {
+                // Executed by their own instruction through `authorize_action`
+                return err!(SSSOneError::ActionRequiresInstruction);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl AdminAction {
+    /// Role changes, authority transfers, supply cap and timelock changes
+    /// wait out the delay while the timelock is enabled
+    pub fn is_timelocked(&self) -> bool {
+        matches!(
+            self,
+            AdminAction::ProposeAuthority { .. }
+                | AdminAction::ProposeRole { .. }
+                | AdminAction::SetTimelockDelay { .. }
+                | AdminAction::SetSupplyCap { .. }
+                | AdminAction::SetReservePolicy { .. }
+        )
+    }
+}
+
+/// Apply `action` right away, or queue it in `operation` when the config's
+/// timelock covers it
+pub fn apply_or_queue(
+    config: &mut Account<StablecoinConfig>,
+    action: AdminAction,
+    actor: Pubkey,
+    payer: Pubkey,
+    operation: Option<&mut Account<TimelockOperation>>,
+    operation_bump: u8,
+) -> Result<()> {
+    let delay = match config.timelock_delay {
+        Some(delay) if action.is_timelocked() => delay,
+        _ => {
+            require!(operation.is_none(), SSSOneError::UnexpectedTimelockOperation);
+            return action.apply(config, actor);
+        }
+    };
+    
+    let operation = operation.ok_or(SSSOneError::TimelockOperationRequired)?;
+    let now = Clock::get()?.unix_timestamp;
+    operation.config = config.key();
+    operation.index = config.timelock_count;
+    operation.action = action.clone();
+    operation.proposer = actor;
+    operation.payer = payer;
+    operation.queued_at = now;
+    operation.eta = now.checked_add(delay).ok_or(SSSOneError::Overflow)?;
+    operation.bump = operation_bump;
+    
+    config.timelock_count = config.timelock_count.checked_add(1)
+        .ok_or(SSSOneError::Overflow)?;
+    
+    emit!(OperationQueued {
+        operation: operation.key(),
+        index: operation.index,
+        action,
+        eta: operation.eta,
+    });
+    msg!("Admin action queued, executable at {}", operation.eta);
+    Ok(())
+}
+
+/// Check that an unexecuted proposal has enough approvals from current
+/// signers, then mark it executed
+pub fn consume_proposal(
+    multisig: &MultisigConfig,
+    proposal: &mut Proposal,
+) -> Result<()> {
+    require!(!proposal.executed, SSSOneError::ProposalAlreadyExecuted);
+    
+    // Approvals from removed signers no longer count
+    let approvals = proposal.approvals.iter()
+        .filter(|approver| multisig.is_signer(approver))
+        .count();
+    require!(
+        approvals >= multisig.threshold as usize,
+        SSSOneError::InsufficientApprovals
+    );
+    proposal.executed = true;
+    Ok(())
+}
+
+/// Authorize an action executed by its own instruction. Without a multisig
+/// the authority key signs; once one is enabled, a multisig signer executes
+/// an approved proposal for exactly this action. Returns the acting key.
+pub fn authorize_action(
+    config: &Account<StablecoinConfig>,
+    signer: Pubkey,
+    multisig: Option<&Account<MultisigConfig>>,
+    proposal: Option<&mut Account<Proposal>>,
+    action: AdminAction,
+) -> Result<Pubkey> {
+    let multisig_key = match config.multisig {
+        Some(multisig_key) => multisig_key,
+        None => {
+            require!(signer == config.authority, SSSOneError::UnauthorizedAuthority);
+            require!(
+                multisig.is_none() && proposal.is_none(),
+                SSSOneError::ProposalMismatch
+            );
+            return Ok(signer);
+        }
+    };
+    
+    let multisig = multisig.ok_or(SSSOneError::MultisigRequired)?;
+    let proposal = proposal.ok_or(SSSOneError::ProposalRequired)?;
+    require!(multisig.key() == multisig_key, SSSOneError::MultisigRequired);
+    require!(multisig.config == config.key(), SSSOneError::MultisigRequired);
+    require!(multisig.is_signer(&signer), SSSOneError::NotMultisigSigner);
+    require!(
+        proposal.multisig == multisig_key && proposal.action == action,
+        SSSOneError::ProposalMismatch
+    );
+    consume_proposal(multisig, proposal)?;
+    
+    emit!(ProposalExecuted {
+        proposal: proposal.key(),
+        executor: signer,
+    });
+    Ok(multisig_key)
+}
+
+impl<'info> AuthorityManagement<'info> {
+    /// Apply an action signed by the authority key, queueing it when the
+    /// timelock is enabled. Once a multisig is enabled, actions must go
+    /// through a proposal instead.
+    pub fn apply(&mut self, action: AdminAction, operation_bump: u8) -> Result<()> {
+        require!(self.config.multisig.is_none(), SSSOneError::MultisigRequired);
+        let authority = self.authority.key();
+        apply_or_queue(
+            &mut self.config,
+            action,
+            authority,
+            authority,
+            self.timelock_operation.as_mut(),
+            operation_bump,
+        )
+    }
+}
@@ -8,33 +8,195 @@ pub enum SSSOneError {
     #[msg("Program is already initialized")]
     AlreadyInitialized,
     
-    #[msg("Program is paused")]
+    #[msg("Operation is paused")]
     ProgramPaused,
     
-    #[msg("Program is already paused")]
+    #[msg("Operations are already paused")]
     AlreadyPaused,
     
-    #[msg("Program is not paused")]
+    #[msg("Operations are not paused")]
     NotPaused,
     
+    #[msg("Invalid pause mask")]
+    InvalidPauseMask,
+    
     #[msg("Unauthorized: Not a valid authority")]
     UnauthorizedAuthority,
     
     #[msg("Unauthorized: Not a valid minter")]
     UnauthorizedMinter,
     
+    #[msg("Unauthorized: Not a valid master minter")]
+    UnauthorizedMasterMinter,
+    
+    #[msg("Mint amount exceeds minter allowance")]
+    MinterAllowanceExceeded,
+    
+    #[msg("Mint amount exceeds rate limit for the current window")]
+    RateLimitExceeded,
+    
+    #[msg("Invalid rate limit window")]
+    InvalidRateLimit,
+    
+    #[msg("Mint would exceed the supply cap")]
+    SupplyCapExceeded,
+    
     #[msg("Unauthorized: Not a valid burner")]
     UnauthorizedBurner,
     
     #[msg("Unauthorized: Not a valid pauser")]
     UnauthorizedPauser,
     
+    #[msg("Unauthorized: Not a valid blacklister")]
+    UnauthorizedBlacklister,
+    
+    #[msg("Unauthorized: Not a valid freezer")]
+    UnauthorizedFreezer,
+    
+    #[msg("Unauthorized: Not a valid KYC officer")]
+    UnauthorizedKycOfficer,
+    
+    #[msg("Unauthorized: Not a valid attestor")]
+    UnauthorizedAttestor,
+    
+    #[msg("Unauthorized: Not a valid metadata authority")]
+    UnauthorizedMetadataAuthority,
+    
+    #[msg("Unauthorized: Not a valid fee admin")]
+    UnauthorizedFeeAdmin,
+    
+    #[msg("Unauthorized: Not a valid rate authority")]
+    UnauthorizedRateAuthority,
+    
+    #[msg("Holder has no KYC record")]
+    KycRequired,
+    
+    #[msg("Holder KYC approval has expired")]
+    KycExpired,
+    
+    #[msg("Redemption ticket is not pending")]
+    RedemptionNotPending,
+    
+    #[msg("Config is already at the current version")]
+    ConfigAlreadyMigrated,
+    
+    #[msg("Config must be migrated to the current version")]
+    ConfigNotMigrated,
+    
+    #[msg("Config belongs to a different mint")]
+    ConfigMintMismatch,
+    
+    #[msg("Transfer fee extension is not enabled on this mint")]
+    TransferFeeNotEnabled,
+    
+    #[msg("Transfer fee exceeds 100%")]
+    InvalidTransferFee,
+    
+    #[msg("Interest-bearing extension is not enabled on this mint")]
+    InterestBearingNotEnabled,
+    
+    #[msg("Confidential transfer extension is not enabled on this mint")]
+    ConfidentialTransferNotEnabled,
+    
+    #[msg("Auditor ElGamal public key must be non-zero")]
+    InvalidAuditorKey,
+    
+    #[msg("Invalid reserve policy")]
+    InvalidReservePolicy,
+    
+    #[msg("Reserve attestation required")]
+    ReserveAttestationRequired,
+    
+    #[msg("Reserve attestation is stale")]
+    ReserveAttestationStale,
+    
+    #[msg("Mint would exceed attested reserves")]
+    InsufficientReserves,
+    
+    #[msg("Invalid attestation timestamp")]
+    InvalidAttestationTimestamp,
+    
+    #[msg("Unauthorized: Not the pending authority")]
+    UnauthorizedPendingAuthority,
+    
+    #[msg("Unauthorized: Not the pending role holder")]
+    UnauthorizedPendingRole,
+    
+    #[msg("No transfer is pending")]
+    NoPendingTransfer,
+    
+    #[msg("Admin actions must be approved through the multisig")]
+    MultisigRequired,
+    
+    #[msg("Multisig is already enabled")]
+    MultisigAlreadyEnabled,
+    
+    #[msg("Unauthorized: Not a multisig signer")]
+    NotMultisigSigner,
+    
+    #[msg("Too many multisig signers")]
+    TooManySigners,
+    
+    #[msg("Duplicate multisig signer")]
+    DuplicateSigner,
+    
+    #[msg("Invalid multisig threshold")]
+    InvalidThreshold,
+    
+    #[msg("Proposal already approved by this signer")]
+    AlreadyApproved,
+    
+    #[msg("Proposal already executed")]
+    ProposalAlreadyExecuted,
+    
+    #[msg("Proposal does not have enough approvals")]
+    InsufficientApprovals,
+    
+    #[msg("A multisig proposal is required for this action")]
+    ProposalRequired,
+    
+    #[msg("Proposal does not match this action")]
+    ProposalMismatch,
+    
+    #[msg("Action must be executed through its own instruction")]
+    ActionRequiresInstruction,
+    
+    #[msg("Unauthorized: Not a valid guardian")]
+    UnauthorizedGuardian,
+    
+    #[msg("Timelock is enabled: a timelock operation account is required")]
+    TimelockOperationRequired,
+    
+    #[msg("Timelock operation account not expected for this action")]
+    UnexpectedTimelockOperation,
+    
+    #[msg("Timelock operation is not ready")]
+    TimelockNotReady,
+    
+    #[msg("Invalid timelock delay")]
+    InvalidTimelockDelay,
+    
     #[msg("Account is frozen")]
     AccountFrozen,
     
     #[msg("Account is not frozen")]
     AccountNotFrozen,
     
+    #[msg("Token account is not owned by the holder")]
+    HolderMismatch,
+    
+    #[msg("Allowlist mints require the holder's token account")]
+    HolderTokenAccountRequired,
+    
+    #[msg("Batch amounts must match the recipient accounts")]
+    BatchLengthMismatch,
+    
+    #[msg("Recipient token account belongs to a different mint")]
+    InvalidRecipientMint,
+    
+    #[msg("Token account is not owned by the recipient")]
+    RecipientMismatch,
+    
     #[msg("Invalid amount")]
     InvalidAmount,
     
@@ -58,4 +220,10 @@ pub enum SSSOneError {
     
     #[msg("Token URI too long")]
     URITooLong,
+    
+    #[msg("Mint does not have a permanent delegate")]
+    PermanentDelegateNotEnabled,
+    
+    #[msg("Mint extensions require the Token-2022 program")]
+    ExtensionsRequireToken2022,
 }
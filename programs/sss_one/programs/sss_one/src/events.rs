@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::{AdminAction, ReservePolicy, Role};
 
 // ========================================================================
 // INITIALIZATION EVENTS
@@ -12,6 +13,27 @@ pub struct StablecoinInitialized {
     pub authority: Pubkey,
 }
 
+#[event]
+pub struct ConfigMigrated {
+    pub legacy_config: Pubkey,
+    pub config: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+#[event]
+pub struct MetadataUpdated {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub metadata_authority: Pubkey,
+}
+
+#[event]
+pub struct MetadataAuthorityUpdated {
+    pub new_metadata_authority: Pubkey,
+}
+
 // ========================================================================
 // MINT EVENTS
 // ========================================================================
@@ -20,11 +42,76 @@ pub struct TokensMinted {
     pub recipient: Pubkey,
     pub amount: u64,
     pub new_supply: u64,
+    /// Off-chain reference passed to `mint_with_reference`
+    pub reference: Option<[u8; 32]>,
+}
+
+#[event]
+pub struct BatchMinted {
+    pub recipients: u32,
+    pub total_amount: u64,
+    pub new_supply: u64,
+    pub minter: Pubkey,
+}
+
+#[event]
+pub struct MinterConfigured {
+    pub minter: Pubkey,
+    pub previous_allowance: u64,
+    pub new_allowance: u64,
+}
+
+#[event]
+pub struct MinterAllowanceIncreased {
+    pub minter: Pubkey,
+    pub previous_allowance: u64,
+    pub new_allowance: u64,
+}
+
+#[event]
+pub struct MinterRemoved {
+    pub minter: Pubkey,
+    pub previous_allowance: u64,
+    pub new_allowance: u64,
 }
 
 #[event]
-pub struct MinterUpdated {
-    pub new_minter: Pubkey,
+pub struct SupplyCapUpdated {
+    pub previous_cap: Option<u64>,
+    pub new_cap: Option<u64>,
+}
+
+#[event]
+pub struct SupplyDiscrepancy {
+    pub config_supply: u64,
+    pub mint_supply: u64,
+    pub auto_paused: bool,
+}
+
+#[event]
+pub struct SupplySynced {
+    pub previous_supply: u64,
+    pub new_supply: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct PauseOnSupplyDiscrepancyUpdated {
+    pub enabled: bool,
+}
+
+/// `minter` is `None` for the config-wide limit, `max_amount` is `None`
+/// when the limit is removed
+#[event]
+pub struct MintRateLimitUpdated {
+    pub minter: Option<Pubkey>,
+    pub max_amount: Option<u64>,
+    pub window_seconds: i64,
+}
+
+#[event]
+pub struct MasterMinterUpdated {
+    pub new_master_minter: Pubkey,
 }
 
 // ========================================================================
@@ -42,6 +129,167 @@ pub struct BurnerUpdated {
     pub new_burner: Pubkey,
 }
 
+// ========================================================================
+// REDEMPTION EVENTS
+// ========================================================================
+#[event]
+pub struct RedemptionRequested {
+    pub holder: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+    pub payout_reference: [u8; 32],
+    pub new_supply: u64,
+}
+
+#[event]
+pub struct RedemptionTicketCreated {
+    pub ticket: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub payout_reference: [u8; 32],
+}
+
+#[event]
+pub struct RedemptionSettled {
+    pub ticket: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub payout_reference: [u8; 32],
+    pub new_supply: u64,
+}
+
+#[event]
+pub struct RedemptionRejected {
+    pub ticket: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub payout_reference: [u8; 32],
+}
+
+#[event]
+pub struct RedeemRequiresKycUpdated {
+    pub enabled: bool,
+}
+
+// ========================================================================
+// KYC EVENTS
+// ========================================================================
+#[event]
+pub struct HolderApproved {
+    pub holder: Pubkey,
+    pub tier: u8,
+    pub expires_at: Option<i64>,
+    pub kyc_officer: Pubkey,
+}
+
+#[event]
+pub struct HolderRevoked {
+    pub holder: Pubkey,
+    pub kyc_officer: Pubkey,
+}
+
+#[event]
+pub struct KycOfficerUpdated {
+    pub new_kyc_officer: Pubkey,
+}
+
+// ========================================================================
+// RESERVE EVENTS
+// ========================================================================
+#[event]
+pub struct ReservesAttested {
+    pub total_reserves: u64,
+    pub timestamp: i64,
+    pub report_hash: [u8; 32],
+    pub attestor: Pubkey,
+}
+
+#[event]
+pub struct ReservePolicyUpdated {
+    pub previous_policy: Option<ReservePolicy>,
+    pub new_policy: Option<ReservePolicy>,
+}
+
+#[event]
+pub struct AttestorUpdated {
+    pub new_attestor: Pubkey,
+}
+
+// ========================================================================
+// TRANSFER FEE EVENTS
+// ========================================================================
+#[event]
+pub struct TransferFeeUpdated {
+    pub basis_points: u16,
+    pub max_fee: u64,
+    /// Epoch from which the new fee applies
+    pub effective_epoch: u64,
+    pub fee_admin: Pubkey,
+}
+
+#[event]
+pub struct TransferFeesHarvested {
+    /// Token accounts the withheld fees were harvested from
+    pub sources: u32,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TransferFeesWithdrawn {
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub fee_admin: Pubkey,
+}
+
+#[event]
+pub struct FeeAdminUpdated {
+    pub new_fee_admin: Pubkey,
+}
+
+// ========================================================================
+// INTEREST EVENTS
+// ========================================================================
+#[event]
+pub struct InterestRateUpdated {
+    /// Annual rate in basis points
+    pub rate: i16,
+    /// Time from which the rate accrues
+    pub effective_timestamp: i64,
+    pub rate_authority: Pubkey,
+}
+
+#[event]
+pub struct RateAuthorityUpdated {
+    pub new_rate_authority: Pubkey,
+}
+
+// ========================================================================
+// CONFIDENTIAL TRANSFER EVENTS
+// ========================================================================
+#[event]
+pub struct ConfidentialAccountApproved {
+    pub token_account: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct AuditorKeyRotated {
+    pub previous_auditor_elgamal_pubkey: Option<[u8; 32]>,
+    pub new_auditor_elgamal_pubkey: [u8; 32],
+    pub authority: Pubkey,
+}
+
+// ========================================================================
+// SEIZE EVENTS
+// ========================================================================
+#[event]
+pub struct TokensSeized {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub case_reference: [u8; 32],
+}
+
 // ========================================================================
 // FREEZE/THAW EVENTS
 // ========================================================================
@@ -55,28 +303,154 @@ pub struct AccountThawed {
     pub account: Pubkey,
 }
 
+#[event]
+pub struct FreezerUpdated {
+    pub new_freezer: Pubkey,
+}
+
 // ========================================================================
 // PAUSE EVENTS
 // ========================================================================
 #[event]
-pub struct ProgramPaused {
+pub struct OperationsPaused {
+    /// `PauseFlags` that were newly paused
+    pub flags: u8,
     pub authority: Pubkey,
 }
 
 #[event]
-pub struct ProgramUnpaused {
+pub struct OperationsUnpaused {
+    /// `PauseFlags` that were newly unpaused
+    pub flags: u8,
     pub authority: Pubkey,
 }
 
 // ========================================================================
 // AUTHORITY EVENTS
 // ========================================================================
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
 #[event]
 pub struct AuthorityTransferred {
     pub new_authority: Pubkey,
 }
 
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct GuardianUpdated {
+    pub new_guardian: Pubkey,
+}
+
+#[event]
+pub struct RoleTransferProposed {
+    pub role: Role,
+    pub pending_holder: Pubkey,
+}
+
+#[event]
+pub struct RoleTransferCancelled {
+    pub role: Role,
+    pub pending_holder: Pubkey,
+}
+
 #[event]
 pub struct PauserUpdated {
     pub new_pauser: Pubkey,
 }
+
+#[event]
+pub struct BlacklisterUpdated {
+    pub new_blacklister: Pubkey,
+}
+
+// ========================================================================
+// BLACKLIST EVENTS
+// ========================================================================
+#[event]
+pub struct Blacklisted {
+    pub wallet: Pubkey,
+    pub reason_code: u8,
+    pub case_reference: [u8; 32],
+    pub blacklister: Pubkey,
+}
+
+#[event]
+pub struct Unblacklisted {
+    pub wallet: Pubkey,
+    pub blacklister: Pubkey,
+}
+
+// ========================================================================
+// MULTISIG EVENTS
+// ========================================================================
+#[event]
+pub struct MultisigCreated {
+    pub multisig: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct MultisigUpdated {
+    pub multisig: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub proposal: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub proposal: Pubkey,
+    pub executor: Pubkey,
+}
+
+// ========================================================================
+// TIMELOCK EVENTS
+// ========================================================================
+#[event]
+pub struct TimelockDelayUpdated {
+    pub delay: Option<i64>,
+}
+
+#[event]
+pub struct OperationQueued {
+    pub operation: Pubkey,
+    pub index: u64,
+    pub action: AdminAction,
+    pub eta: i64,
+}
+
+#[event]
+pub struct OperationExecuted {
+    pub operation: Pubkey,
+    pub index: u64,
+}
+
+#[event]
+pub struct OperationCancelled {
+    pub operation: Pubkey,
+    pub index: u64,
+    pub guardian: Pubkey,
+}
@@ -0,0 +1,386 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        confidential_transfer, confidential_transfer::ConfidentialTransferMint,
+        default_account_state, default_account_state::DefaultAccountState,
+        interest_bearing_mint, interest_bearing_mint::InterestBearingConfig, metadata_pointer,
+        permanent_delegate::PermanentDelegate, transfer_fee,
+        transfer_fee::TransferFeeConfig, transfer_hook, BaseStateWithExtensions, ExtensionType,
+        StateWithExtensions,
+    },
+    solana_zk_token_sdk::zk_token_elgamal::pod::ElGamalPubkey,
+    state::{AccountState, Mint as MintState},
+};
+use spl_token_metadata_interface::state::{Field, TokenMetadata};
+use crate::error::SSSOneError;
+use crate::state::MintExtension;
+
+impl MintExtension {
+    pub fn extension_type(&self) -> ExtensionType {
+        match self {
+            MintExtension::MintCloseAuthority => ExtensionType::MintCloseAuthority,
+            MintExtension::TransferHook { .. } => ExtensionType::TransferHook,
+            MintExtension::PermanentDelegate => ExtensionType::PermanentDelegate,
+            MintExtension::TransferFee { .. } => ExtensionType::TransferFeeConfig,
+            MintExtension::InterestBearing { .. } => ExtensionType::InterestBearingConfig,
+            MintExtension::ConfidentialTransfer { .. } => ExtensionType::ConfidentialTransferMint,
+            MintExtension::DefaultAccountStateFrozen => ExtensionType::DefaultAccountState,
+        }
+    }
+}
+
+/// Account size needed for a mint carrying the given extensions, plus the
+/// metadata pointer when `metadata` is set. The token metadata itself is
+/// variable-length and reallocated by Token-2022 on initialization.
+pub fn mint_space(extensions: &[MintExtension], metadata: bool) -> Result<usize> {
+    let mut extension_types: Vec<ExtensionType> = extensions
+        .iter()
+        .map(MintExtension::extension_type)
+        .collect();
+    if metadata {
+        extension_types.push(ExtensionType::MetadataPointer);
+    }
+    Ok(ExtensionType::try_calculate_account_len::<MintState>(&extension_types)?)
+}
+
+/// Size of the token metadata entry written to the mint
+pub fn token_metadata_space(
+    mint: &Pubkey,
+    update_authority: &Pubkey,
+    name: &str,
+    symbol: &str,
+    uri: &str,
+) -> Result<usize> {
+    let metadata = TokenMetadata {
+        update_authority: Some(*update_authority).try_into()?,
+        mint: *mint,
+        name: name.to_string(),
+        symbol: symbol.to_string(),
+        uri: uri.to_string(),
+        additional_metadata: vec![],
+    };
+    Ok(metadata.tlv_size_of()?)
+}
+
+/// Initialize a single extension on an allocated but uninitialized mint.
+/// Must run before `InitializeMint2`.
+pub fn initialize_extension<'info>(
+    extension: &MintExtension,
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &Pubkey,
+    permanent_delegate: &Pubkey,
+) -> Result<()> {
+    let ix = match extension {
+        MintExtension::MintCloseAuthority => {
+            spl_token_2022::instruction::initialize_mint_close_authority(
+                token_program.key,
+                mint.key,
+                Some(authority),
+            )?
+        }
+        MintExtension::TransferHook { program_id } => {
+            transfer_hook::instruction::initialize(
+                token_program.key,
+                mint.key,
+                Some(*authority),
+                Some(*program_id),
+            )?
+        }
+        MintExtension::PermanentDelegate => {
+            spl_token_2022::instruction::initialize_permanent_delegate(
+                token_program.key,
+                mint.key,
+                permanent_delegate,
+            )?
+        }
+        MintExtension::TransferFee { basis_points, max_fee } => {
+            transfer_fee::instruction::initialize_transfer_fee_config(
+                token_program.key,
+                mint.key,
+                Some(authority),
+                Some(authority),
+                *basis_points,
+                *max_fee,
+            )?
+        }
+        MintExtension::InterestBearing { rate } => {
+            interest_bearing_mint::instruction::initialize(
+                token_program.key,
+                mint.key,
+                Some(*authority),
+                *rate,
+            )?
+        }
+        MintExtension::ConfidentialTransfer { auditor_elgamal_pubkey } => {
+            confidential_transfer::instruction::initialize_mint(
+                token_program.key,
+                mint.key,
+                Some(*authority),
+                false, // accounts need approval
+                Some(ElGamalPubkey(*auditor_elgamal_pubkey)),
+            )?
+        }
+        MintExtension::DefaultAccountStateFrozen => {
+            default_account_state::instruction::initialize_default_account_state(
+                token_program.key,
+                mint.key,
+                &AccountState::Frozen,
+            )?
+        }
+    };
+    invoke(&ix, std::slice::from_ref(mint))?;
+    Ok(())
+}
+
+/// Point the mint's metadata at the mint itself. Must run before
+/// `InitializeMint2`.
+pub fn initialize_metadata_pointer<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &Pubkey,
+) -> Result<()> {
+    let ix = metadata_pointer::instruction::initialize(
+        token_program.key,
+        mint.key,
+        Some(*authority),
+        Some(*mint.key),
+    )?;
+    invoke(&ix, std::slice::from_ref(mint))?;
+    Ok(())
+}
+
+/// Write the token metadata into the mint, signed by the mint authority PDA
+/// which also becomes the metadata update authority
+pub fn initialize_token_metadata<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    mint_authority: &AccountInfo<'info>,
+    name: String,
+    symbol: String,
+    uri: String,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = spl_token_metadata_interface::instruction::initialize(
+        token_program.key,
+        mint.key,
+        mint_authority.key,
+        mint.key,
+        mint_authority.key,
+        name,
+        symbol,
+        uri,
+    );
+    invoke_signed(&ix, &[mint.clone(), mint_authority.clone()], signer_seeds)?;
+    Ok(())
+}
+
+/// Update a single token metadata field, signed by the update authority PDA
+pub fn update_token_metadata_field<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    update_authority: &AccountInfo<'info>,
+    field: Field,
+    value: String,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = spl_token_metadata_interface::instruction::update_field(
+        token_program.key,
+        mint.key,
+        update_authority.key,
+        field,
+        value,
+    );
+    invoke_signed(&ix, &[mint.clone(), update_authority.clone()], signer_seeds)?;
+    Ok(())
+}
+
+/// Token metadata stored in the mint, if any
+pub fn token_metadata(mint: &AccountInfo) -> Result<Option<TokenMetadata>> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(None);
+    }
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    Ok(mint.get_variable_len_extension::<TokenMetadata>().ok())
+}
+
+/// Schedule a new transfer fee, signed by the fee authority PDA. Token-2022
+/// applies it two epochs later.
+pub fn set_transfer_fee<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    basis_points: u16,
+    max_fee: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = transfer_fee::instruction::set_transfer_fee(
+        token_program.key,
+        mint.key,
+        authority.key,
+        &[],
+        basis_points,
+        max_fee,
+    )?;
+    invoke_signed(&ix, &[mint.clone(), authority.clone()], signer_seeds)?;
+    Ok(())
+}
+
+/// Move fees withheld in `sources` into the mint
+pub fn harvest_withheld_fees<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    sources: &[AccountInfo<'info>],
+) -> Result<()> {
+    let source_keys: Vec<&Pubkey> = sources.iter().map(|source| source.key).collect();
+    let ix = transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+        token_program.key,
+        mint.key,
+        &source_keys,
+    )?;
+    let mut account_infos = vec![mint.clone()];
+    account_infos.extend_from_slice(sources);
+    invoke(&ix, &account_infos)?;
+    Ok(())
+}
+
+/// Withdraw fees withheld in the mint, signed by the withdraw authority PDA
+pub fn withdraw_withheld_fees<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = transfer_fee::instruction::withdraw_withheld_tokens_from_mint(
+        token_program.key,
+        mint.key,
+        destination.key,
+        authority.key,
+        &[],
+    )?;
+    invoke_signed(
+        &ix,
+        &[mint.clone(), destination.clone(), authority.clone()],
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+/// Update the interest rate, signed by the rate authority PDA
+pub fn update_interest_rate<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    rate: i16,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = interest_bearing_mint::instruction::update_rate(
+        token_program.key,
+        mint.key,
+        authority.key,
+        &[],
+        rate,
+    )?;
+    invoke_signed(&ix, &[mint.clone(), authority.clone()], signer_seeds)?;
+    Ok(())
+}
+
+/// Interest-bearing configuration of a mint
+pub fn interest_bearing_config(mint: &AccountInfo) -> Result<InterestBearingConfig> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    let config = mint
+        .get_extension::<InterestBearingConfig>()
+        .map_err(|_| SSSOneError::InterestBearingNotEnabled)?;
+    Ok(*config)
+}
+
+/// Approve a token account for confidential transfers, signed by the
+/// confidential transfer authority PDA
+pub fn approve_confidential_account<'info>(
+    token_program: &AccountInfo<'info>,
+    token_account: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = confidential_transfer::instruction::approve_account(
+        token_program.key,
+        token_account.key,
+        mint.key,
+        authority.key,
+        &[],
+    )?;
+    invoke_signed(
+        &ix,
+        &[token_account.clone(), mint.clone(), authority.clone()],
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+/// Replace the confidential transfer auditor key, keeping the mint's
+/// approval policy
+pub fn update_auditor_elgamal_pubkey<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    auditor_elgamal_pubkey: [u8; 32],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let auto_approve_new_accounts =
+        bool::from(confidential_transfer_mint(mint)?.auto_approve_new_accounts);
+    let ix = confidential_transfer::instruction::update_mint(
+        token_program.key,
+        mint.key,
+        authority.key,
+        &[],
+        auto_approve_new_accounts,
+        Some(ElGamalPubkey(auditor_elgamal_pubkey)),
+    )?;
+    invoke_signed(&ix, &[mint.clone(), authority.clone()], signer_seeds)?;
+    Ok(())
+}
+
+/// Confidential transfer configuration of a mint
+pub fn confidential_transfer_mint(mint: &AccountInfo) -> Result<ConfidentialTransferMint> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    let config = mint
+        .get_extension::<ConfidentialTransferMint>()
+        .map_err(|_| SSSOneError::ConfidentialTransferNotEnabled)?;
+    Ok(*config)
+}
+
+/// Transfer fee configuration of a mint
+pub fn transfer_fee_config(mint: &AccountInfo) -> Result<TransferFeeConfig> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    let config = mint
+        .get_extension::<TransferFeeConfig>()
+        .map_err(|_| SSSOneError::TransferFeeNotEnabled)?;
+    Ok(*config)
+}
+
+/// Whether new token accounts of a mint start frozen
+pub fn is_default_frozen(mint: &AccountInfo) -> Result<bool> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    Ok(mint
+        .get_extension::<DefaultAccountState>()
+        .is_ok_and(|extension| extension.state == AccountState::Frozen as u8))
+}
+
+/// Permanent delegate configured on a mint, if any
+pub fn permanent_delegate(mint: &AccountInfo) -> Result<Option<Pubkey>> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    Ok(mint
+        .get_extension::<PermanentDelegate>()
+        .ok()
+        .and_then(|extension| Option::<Pubkey>::from(extension.delegate)))
+}
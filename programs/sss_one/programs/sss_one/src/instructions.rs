@@ -1,40 +1,140 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Mint};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::state::*;
+use crate::error::SSSOneError;
 
 // ========================================================================
 // INITIALIZATION
 // ========================================================================
 #[derive(Accounts)]
+#[instruction(mint_seed: u64)]
 pub struct Initialize<'info> {
+    /// CHECK: Mint PDA seeded by the authority and an issuer-chosen seed,
+    /// allocated and initialized by the handler so that Token-2022
+    /// extensions can be set up before the mint
+    #[account(
+        mut,
+        seeds = [b"mint", authority.key().as_ref(), &mint_seed.to_le_bytes()],
+        bump,
+    )]
+    pub mint: UncheckedAccount<'info>,
+    
     #[account(
         init,
         payer = authority,
         space = 8 + StablecoinConfig::SIZE,
+        seeds = [b"config", mint.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    /// CHECK: This is the mint authority PDA
+    #[account(
+        seeds = [b"mint_authority", config.key().as_ref()],
+        bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: This is the permanent delegate PDA
+    #[account(
+        seeds = [b"permanent_delegate", config.key().as_ref()],
+        bump,
+    )]
+    pub permanent_delegate: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
     )]
     pub config: Account<'info, StablecoinConfig>,
     
+    #[account(
+        mut,
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Mint authority PDA, the mint metadata's update authority
+    #[account(
+        seeds = [b"mint_authority", config.key().as_ref()],
+        bump = config.mint_authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Funds any extra rent when the mint metadata grows
+    #[account(
+        mut,
+        constraint = metadata_authority.key() == config.metadata_authority @ SSSOneError::UnauthorizedMetadataAuthority,
+    )]
+    pub metadata_authority: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+// ========================================================================
+// CONFIG MIGRATION
+// ========================================================================
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: Version 0 config, a keypair account owned by this program and
+    /// read by the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    pub legacy_config: UncheckedAccount<'info>,
+    
     #[account(
         init,
         payer = authority,
-        mint::decimals = 0, // Will be set in initialize
-        mint::authority = mint_authority,
+        space = 8 + StablecoinConfig::SIZE,
+        seeds = [b"config", mint.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Mint authority PDA of the legacy config, which holds the
+    /// mint and freeze authority until the migration hands them over
+    #[account(
+        seeds = [b"mint_authority", legacy_config.key().as_ref()],
+        bump,
     )]
-    pub mint: Account<'info, Mint>,
+    pub legacy_mint_authority: UncheckedAccount<'info>,
     
-    /// CHECK: This is the mint authority PDA
+    /// CHECK: Mint authority PDA of the migrated config
     #[account(
         seeds = [b"mint_authority", config.key().as_ref()],
         bump,
     )]
     pub mint_authority: UncheckedAccount<'info>,
     
+    /// CHECK: Permanent delegate PDA of the migrated config
+    #[account(
+        seeds = [b"permanent_delegate", config.key().as_ref()],
+        bump,
+    )]
+    pub permanent_delegate: UncheckedAccount<'info>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
 // ========================================================================
@@ -42,106 +142,1268 @@ pub struct Initialize<'info> {
 // ========================================================================
 #[derive(Accounts)]
 pub struct MintOperation<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
     pub config: Account<'info, StablecoinConfig>,
     
     #[account(
         mut,
         constraint = mint.key() == config.mint,
     )]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
     
     /// CHECK: Mint authority PDA
     #[account(
         seeds = [b"mint_authority", config.key().as_ref()],
-        bump,
+        bump = config.mint_authority_bump,
     )]
     pub mint_authority: UncheckedAccount<'info>,
     
-    #[account(mut)]
-    pub recipient_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = recipient_token_account.owner == recipient.key() @ SSSOneError::RecipientMismatch,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
     
-    /// CHECK: Recipient owner
+    /// CHECK: Owner of the recipient token account, reported in the event
     pub recipient: UncheckedAccount<'info>,
     
+    #[account(
+        mut,
+        seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
+        bump = minter_info.bump,
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+    
+    pub minter: Signer<'info>,
+    
+    /// Required when the config enforces a reserve policy
+    #[account(
+        seeds = [b"reserve_attestation", config.key().as_ref()],
+        bump = reserve_attestation.bump,
+    )]
+    pub reserve_attestation: Option<Account<'info, ReserveAttestation>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Recipient token accounts are passed as writable remaining accounts, in
+/// the same order as the amounts
+#[derive(Accounts)]
+pub struct BatchMint<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Mint authority PDA
+    #[account(
+        seeds = [b"mint_authority", config.key().as_ref()],
+        bump = config.mint_authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
+        bump = minter_info.bump,
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+    
     pub minter: Signer<'info>,
     
-    pub token_program: Program<'info, Token>,
+    /// Required when the config enforces a reserve policy
+    #[account(
+        seeds = [b"reserve_attestation", config.key().as_ref()],
+        bump = reserve_attestation.bump,
+    )]
+    pub reserve_attestation: Option<Account<'info, ReserveAttestation>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, reference: [u8; 32])]
+pub struct MintWithReference<'info> {
+    pub mint_operation: MintOperation<'info>,
+    
+    /// Fails to initialize if the reference was already used
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MintReceipt::SIZE,
+        seeds = [b"mint_receipt", mint_operation.config.key().as_ref(), reference.as_ref()],
+        bump,
+    )]
+    pub mint_receipt: Account<'info, MintReceipt>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 // ========================================================================
-// BURN OPERATIONS
+// REDEMPTION
 // ========================================================================
 #[derive(Accounts)]
-pub struct BurnOperation<'info> {
-    #[account(mut)]
+pub struct Redeem<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
     pub config: Account<'info, StablecoinConfig>,
     
     #[account(
         mut,
         constraint = mint.key() == config.mint,
     )]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
     
     #[account(
         mut,
         constraint = token_account.mint == config.mint,
+        constraint = token_account.owner == holder.key() @ SSSOneError::HolderMismatch,
     )]
-    pub token_account: Account<'info, TokenAccount>,
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
     
-    pub burner: Signer<'info>,
+    pub holder: Signer<'info>,
+    
+    /// Required when the config limits redemption to KYC-approved holders
+    #[account(
+        seeds = [b"kyc", config.key().as_ref(), holder.key().as_ref()],
+        bump = kyc_record.bump,
+    )]
+    pub kyc_record: Option<Account<'info, KycRecord>>,
     
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
-// ========================================================================
-// FREEZE/THAW
-// ========================================================================
+/// Remaining accounts: extra accounts required by the mint's transfer hook
 #[derive(Accounts)]
-pub struct FreezeThaw<'info> {
+pub struct RequestRedemption<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
     pub config: Account<'info, StablecoinConfig>,
     
     #[account(
         constraint = mint.key() == config.mint,
     )]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
     
     #[account(
         mut,
         constraint = token_account.mint == config.mint,
+        constraint = token_account.owner == holder.key() @ SSSOneError::HolderMismatch,
     )]
-    pub token_account: Account<'info, TokenAccount>,
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
     
-    /// CHECK: Freezer (must be freeze authority)
-    pub freezer: Signer<'info>,
+    #[account(
+        init,
+        payer = holder,
+        space = 8 + RedemptionTicket::SIZE,
+        seeds = [b"redemption", config.key().as_ref(), &config.redemption_count.to_le_bytes()],
+        bump,
+    )]
+    pub redemption_ticket: Account<'info, RedemptionTicket>,
+    
+    /// Holds the redeemed tokens until the ticket is settled or rejected
+    #[account(
+        init,
+        payer = holder,
+        seeds = [b"redemption_escrow", redemption_ticket.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = redemption_ticket,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Mint authority PDA, which thaws the escrow on allowlist mints
+    #[account(
+        seeds = [b"mint_authority", config.key().as_ref()],
+        bump = config.mint_authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    
+    /// Required when the config limits redemption to KYC-approved holders
+    #[account(
+        seeds = [b"kyc", config.key().as_ref(), holder.key().as_ref()],
+        bump = kyc_record.bump,
+    )]
+    pub kyc_record: Option<Account<'info, KycRecord>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SettleRedemption<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"redemption", config.key().as_ref(), &redemption_ticket.index.to_le_bytes()],
+        bump = redemption_ticket.bump,
+    )]
+    pub redemption_ticket: Account<'info, RedemptionTicket>,
+    
+    #[account(
+        mut,
+        seeds = [b"redemption_escrow", redemption_ticket.key().as_ref()],
+        bump,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        constraint = burner.key() == config.burner @ SSSOneError::UnauthorizedBurner,
+    )]
+    pub burner: Signer<'info>,
+    
+    /// CHECK: Receives the escrow account's rent
+    #[account(
+        mut,
+        address = redemption_ticket.holder,
+    )]
+    pub rent_receiver: UncheckedAccount<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Remaining accounts: extra accounts required by the mint's transfer hook
+#[derive(Accounts)]
+pub struct RejectRedemption<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    /// Writable to harvest fees withheld in the escrow
+    #[account(
+        mut,
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"redemption", config.key().as_ref(), &redemption_ticket.index.to_le_bytes()],
+        bump = redemption_ticket.bump,
+    )]
+    pub redemption_ticket: Account<'info, RedemptionTicket>,
+    
+    #[account(
+        mut,
+        seeds = [b"redemption_escrow", redemption_ticket.key().as_ref()],
+        bump,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        address = redemption_ticket.token_account,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        constraint = burner.key() == config.burner @ SSSOneError::UnauthorizedBurner,
+    )]
+    pub burner: Signer<'info>,
+    
+    /// CHECK: Receives the escrow account's rent
+    #[account(
+        mut,
+        address = redemption_ticket.holder,
+    )]
+    pub rent_receiver: UncheckedAccount<'info>,
     
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 // ========================================================================
-// PAUSE MANAGEMENT
+// SUPPLY RECONCILIATION
 // ========================================================================
 #[derive(Accounts)]
-pub struct PauseManagement<'info> {
-    #[account(mut)]
+pub struct ReconcileSupply<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
     pub config: Account<'info, StablecoinConfig>,
     
     #[account(
-        constraint = pauser.key() == config.pauser,
+        constraint = mint.key() == config.mint,
     )]
-    pub pauser: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+#[derive(Accounts)]
+pub struct SyncSupply<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// Authority key, or a multisig signer executing an approved proposal
+    pub authority: Signer<'info>,
+    
+    /// Multisig and the proposal it approved, required once a multisig is enabled
+    pub multisig: Option<Account<'info, MultisigConfig>>,
+    
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
 }
 
 // ========================================================================
-// AUTHORITY MANAGEMENT
+// MINTER MANAGEMENT
 // ========================================================================
 #[derive(Accounts)]
-pub struct AuthorityManagement<'info> {
-    #[account(mut)]
+pub struct ConfigureMinter<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
     pub config: Account<'info, StablecoinConfig>,
     
     #[account(
-        constraint = authority.key() == config.authority,
+        init_if_needed,
+        payer = master_minter,
+        space = 8 + MinterInfo::SIZE,
+        seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
+        bump,
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+    
+    /// CHECK: Minter being configured
+    pub minter: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = master_minter.key() == config.master_minter @ SSSOneError::UnauthorizedMasterMinter,
+    )]
+    pub master_minter: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MinterManagement<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
+        bump = minter_info.bump,
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+    
+    /// CHECK: Minter being managed
+    pub minter: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = master_minter.key() == config.master_minter @ SSSOneError::UnauthorizedMasterMinter,
+    )]
+    pub master_minter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MinterRateLimitManagement<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
+        bump = minter_info.bump,
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+    
+    /// CHECK: Minter whose limit is being set
+    pub minter: UncheckedAccount<'info>,
+    
+    /// Authority key, or a multisig signer executing an approved proposal
+    pub authority: Signer<'info>,
+    
+    /// Multisig and the proposal it approved, required once a multisig is enabled
+    pub multisig: Option<Account<'info, MultisigConfig>>,
+    
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveMinter<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        close = master_minter,
+        seeds = [b"minter", config.key().as_ref(), minter.key().as_ref()],
+        bump = minter_info.bump,
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+    
+    /// CHECK: Minter being removed
+    pub minter: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = master_minter.key() == config.master_minter @ SSSOneError::UnauthorizedMasterMinter,
+    )]
+    pub master_minter: Signer<'info>,
+}
+
+// ========================================================================
+// BURN OPERATIONS
+// ========================================================================
+#[derive(Accounts)]
+pub struct BurnOperation<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = token_account.mint == config.mint,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub burner: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// ========================================================================
+// PROOF OF RESERVE
+// ========================================================================
+#[derive(Accounts)]
+pub struct AttestReserves<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = attestor,
+        space = 8 + ReserveAttestation::SIZE,
+        seeds = [b"reserve_attestation", config.key().as_ref()],
+        bump,
+    )]
+    pub reserve_attestation: Account<'info, ReserveAttestation>,
+    
+    #[account(
+        mut,
+        constraint = attestor.key() == config.attestor @ SSSOneError::UnauthorizedAttestor,
+    )]
+    pub attestor: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+// ========================================================================
+// TRANSFER FEES
+// ========================================================================
+#[derive(Accounts)]
+pub struct TransferFeeManagement<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Mint authority PDA, the transfer fee authority
+    #[account(
+        seeds = [b"mint_authority", config.key().as_ref()],
+        bump = config.mint_authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = fee_admin.key() == config.fee_admin @ SSSOneError::UnauthorizedFeeAdmin,
+    )]
+    pub fee_admin: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Remaining accounts: token accounts to harvest withheld fees from
+#[derive(Accounts)]
+pub struct HarvestTransferFees<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        constraint = fee_admin.key() == config.fee_admin @ SSSOneError::UnauthorizedFeeAdmin,
+    )]
+    pub fee_admin: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTransferFees<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Mint authority PDA, the transfer fee authority
+    #[account(
+        seeds = [b"mint_authority", config.key().as_ref()],
+        bump = config.mint_authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = treasury_token_account.mint == config.mint,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        constraint = fee_admin.key() == config.fee_admin @ SSSOneError::UnauthorizedFeeAdmin,
+    )]
+    pub fee_admin: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// ========================================================================
+// INTEREST
+// ========================================================================
+#[derive(Accounts)]
+pub struct InterestRateManagement<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Mint authority PDA, the interest rate authority
+    #[account(
+        seeds = [b"mint_authority", config.key().as_ref()],
+        bump = config.mint_authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = rate_authority.key() == config.rate_authority @ SSSOneError::UnauthorizedRateAuthority,
+    )]
+    pub rate_authority: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// ========================================================================
+// CONFIDENTIAL TRANSFERS
+// ========================================================================
+#[derive(Accounts)]
+pub struct ApproveConfidentialAccount<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = token_account.mint == config.mint,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Mint authority PDA, the confidential transfer authority
+    #[account(
+        seeds = [b"mint_authority", config.key().as_ref()],
+        bump = config.mint_authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Authority key, or a multisig signer executing an approved proposal
+    pub authority: Signer<'info>,
+    
+    /// Multisig and the proposal it approved, required once a multisig is enabled
+    pub multisig: Option<Account<'info, MultisigConfig>>,
+    
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct RotateAuditorKey<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Mint authority PDA, the confidential transfer authority
+    #[account(
+        seeds = [b"mint_authority", config.key().as_ref()],
+        bump = config.mint_authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Authority key, or a multisig signer executing an approved proposal
+    pub authority: Signer<'info>,
+    
+    /// Multisig and the proposal it approved, required once a multisig is enabled
+    pub multisig: Option<Account<'info, MultisigConfig>>,
+    
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// ========================================================================
+// SEIZE
+// ========================================================================
+/// Remaining accounts: extra accounts required by the mint's transfer hook
+#[derive(Accounts)]
+pub struct Seize<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Permanent delegate PDA
+    #[account(
+        seeds = [b"permanent_delegate", config.key().as_ref()],
+        bump = config.permanent_delegate_bump,
+    )]
+    pub permanent_delegate: UncheckedAccount<'info>,
+    
+    /// CHECK: Mint authority PDA, which thaws a frozen source for the
+    /// seizure and freezes it again
+    #[account(
+        seeds = [b"mint_authority", config.key().as_ref()],
+        bump = config.mint_authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = source_token_account.mint == config.mint,
+    )]
+    pub source_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Seizure is only possible from blacklisted owners
+    #[account(
+        seeds = [b"blacklist", config.key().as_ref(), source_token_account.owner.as_ref()],
+        bump = blacklist_entry.bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+    
+    #[account(
+        mut,
+        constraint = treasury_token_account.mint == config.mint,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Authority key, or a multisig signer executing an approved proposal
+    pub authority: Signer<'info>,
+    
+    /// Multisig and the proposal it approved, required once a multisig is enabled
+    pub multisig: Option<Account<'info, MultisigConfig>>,
+    
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// ========================================================================
+// FREEZE/THAW
+// ========================================================================
+#[derive(Accounts)]
+pub struct FreezeThaw<'info> {
+    /// Not checked against the current version, so incident response never
+    /// waits on a layout migration
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Mint authority PDA, which is also the freeze authority
+    #[account(
+        seeds = [b"mint_authority", config.key().as_ref()],
+        bump = config.mint_authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = token_account.mint == config.mint,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        constraint = freezer.key() == config.freezer @ SSSOneError::UnauthorizedFreezer,
+    )]
+    pub freezer: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// ========================================================================
+// PAUSE MANAGEMENT
+// ========================================================================
+#[derive(Accounts)]
+pub struct PauseManagement<'info> {
+    /// Not checked against the current version, so incident response never
+    /// waits on a layout migration
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        constraint = pauser.key() == config.pauser,
+    )]
+    pub pauser: Signer<'info>,
+}
+
+// ========================================================================
+// AUTHORITY MANAGEMENT
+// ========================================================================
+#[derive(Accounts)]
+pub struct AuthorityManagement<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority,
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Queued operation, required when the timelock covers the action
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TimelockOperation::SIZE,
+        seeds = [b"timelock", config.key().as_ref(), config.timelock_count.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub timelock_operation: Option<Account<'info, TimelockOperation>>,
+    
+    pub system_program: Option<Program<'info, System>>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    pub pending_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptRole<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    pub pending_holder: Signer<'info>,
+}
+
+// ========================================================================
+// MULTISIG
+// ========================================================================
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + MultisigConfig::SIZE,
+        seeds = [b"multisig", config.key().as_ref()],
+        bump,
+    )]
+    pub multisig: Account<'info, MultisigConfig>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority,
     )]
     pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.config.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, MultisigConfig>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::SIZE,
+        seeds = [b"proposal", multisig.key().as_ref(), multisig.proposal_count.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        mut,
+        constraint = multisig.is_signer(&proposer.key()) @ SSSOneError::NotMultisigSigner,
+    )]
+    pub proposer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    pub multisig: Account<'info, MultisigConfig>,
+    
+    #[account(
+        mut,
+        has_one = multisig,
+        seeds = [b"proposal", multisig.key().as_ref(), proposal.index.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        constraint = multisig.is_signer(&approver.key()) @ SSSOneError::NotMultisigSigner,
+    )]
+    pub approver: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+        constraint = config.multisig == Some(multisig.key()) @ SSSOneError::MultisigRequired,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        has_one = config,
+    )]
+    pub multisig: Account<'info, MultisigConfig>,
+    
+    #[account(
+        mut,
+        has_one = multisig,
+        seeds = [b"proposal", multisig.key().as_ref(), proposal.index.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        mut,
+        constraint = multisig.is_signer(&executor.key()) @ SSSOneError::NotMultisigSigner,
+    )]
+    pub executor: Signer<'info>,
+    
+    /// Queued operation, required when the timelock covers the action
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + TimelockOperation::SIZE,
+        seeds = [b"timelock", config.key().as_ref(), config.timelock_count.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub timelock_operation: Option<Account<'info, TimelockOperation>>,
+    
+    pub system_program: Option<Program<'info, System>>,
+}
+
+// ========================================================================
+// TIMELOCK
+// ========================================================================
+#[derive(Accounts)]
+pub struct ExecuteTimelockOperation<'info> {
+    #[account(
+        mut,
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        close = rent_receiver,
+        has_one = config,
+        seeds = [b"timelock", config.key().as_ref(), timelock_operation.index.to_le_bytes().as_ref()],
+        bump = timelock_operation.bump,
+    )]
+    pub timelock_operation: Account<'info, TimelockOperation>,
+    
+    /// CHECK: Account that paid for the operation, receives its rent
+    #[account(
+        mut,
+        address = timelock_operation.payer,
+    )]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelTimelockOperation<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        close = rent_receiver,
+        has_one = config,
+        seeds = [b"timelock", config.key().as_ref(), timelock_operation.index.to_le_bytes().as_ref()],
+        bump = timelock_operation.bump,
+    )]
+    pub timelock_operation: Account<'info, TimelockOperation>,
+    
+    #[account(
+        constraint = guardian.key() == config.guardian @ SSSOneError::UnauthorizedGuardian,
+    )]
+    pub guardian: Signer<'info>,
+    
+    /// CHECK: Account that paid for the operation, receives its rent
+    #[account(
+        mut,
+        address = timelock_operation.payer,
+    )]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
+
+// ========================================================================
+// BLACKLIST MANAGEMENT
+// ========================================================================
+#[derive(Accounts)]
+pub struct AddToBlacklist<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        init,
+        payer = blacklister,
+        space = 8 + BlacklistEntry::SIZE,
+        seeds = [b"blacklist", config.key().as_ref(), wallet.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+    
+    /// CHECK: Wallet being blacklisted
+    pub wallet: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = blacklister.key() == config.blacklister @ SSSOneError::UnauthorizedBlacklister,
+    )]
+    pub blacklister: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveFromBlacklist<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [b"blacklist", config.key().as_ref(), wallet.key().as_ref()],
+        bump = blacklist_entry.bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+    
+    /// CHECK: Wallet being removed from the blacklist
+    pub wallet: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = blacklister.key() == config.blacklister @ SSSOneError::UnauthorizedBlacklister,
+    )]
+    pub blacklister: Signer<'info>,
+    
+    /// CHECK: Receives the entry's rent
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
+
+// ========================================================================
+// KYC MANAGEMENT
+// ========================================================================
+#[derive(Accounts)]
+pub struct ApproveHolder<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = kyc_officer,
+        space = 8 + KycRecord::SIZE,
+        seeds = [b"kyc", config.key().as_ref(), holder.key().as_ref()],
+        bump,
+    )]
+    pub kyc_record: Account<'info, KycRecord>,
+    
+    /// CHECK: Holder being approved
+    pub holder: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Mint authority PDA, which is also the freeze authority
+    #[account(
+        seeds = [b"mint_authority", config.key().as_ref()],
+        bump = config.mint_authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Holder's token account, thawed when the mint is an allowlist
+    #[account(
+        mut,
+        constraint = token_account.mint == config.mint,
+        constraint = token_account.owner == holder.key() @ SSSOneError::HolderMismatch,
+    )]
+    pub token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        constraint = kyc_officer.key() == config.kyc_officer @ SSSOneError::UnauthorizedKycOfficer,
+    )]
+    pub kyc_officer: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeHolder<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.version == StablecoinConfig::CURRENT_VERSION @ SSSOneError::ConfigNotMigrated,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [b"kyc", config.key().as_ref(), holder.key().as_ref()],
+        bump = kyc_record.bump,
+    )]
+    pub kyc_record: Account<'info, KycRecord>,
+    
+    /// CHECK: Holder being revoked
+    pub holder: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = mint.key() == config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Mint authority PDA, which is also the freeze authority
+    #[account(
+        seeds = [b"mint_authority", config.key().as_ref()],
+        bump = config.mint_authority_bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Holder's token account, frozen again. Required when the mint is an
+    /// allowlist.
+    #[account(
+        mut,
+        constraint = token_account.mint == config.mint,
+        constraint = token_account.owner == holder.key() @ SSSOneError::HolderMismatch,
+    )]
+    pub token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        constraint = kyc_officer.key() == config.kyc_officer @ SSSOneError::UnauthorizedKycOfficer,
+    )]
+    pub kyc_officer: Signer<'info>,
+    
+    /// CHECK: Receives the record's rent
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
 }
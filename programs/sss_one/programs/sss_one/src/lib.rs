@@ -1,10 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Mint};
+use anchor_lang::system_program;
+use anchor_spl::token_interface;
+use anchor_spl::token_2022::spl_token_2022::{self, instruction::AuthorityType};
+use spl_token_metadata_interface::state::Field;
 
 pub mod state;
 pub mod instructions;
 pub mod error;
 pub mod events;
+pub mod extensions;
+pub mod admin;
+pub mod mint;
+pub mod migration;
 
 use instructions::*;
 use state::*;
@@ -20,14 +27,24 @@ pub mod sss_one {
     // ========================================================================
     // INITIALIZATION
     // ========================================================================
-    /// Initialize a new SSS-1 minimal stablecoin
+    /// Initialize a new SSS-1 minimal stablecoin. The mint address derives
+    /// from the authority and `mint_seed`, which never change for the mint.
     pub fn initialize(
         ctx: Context<Initialize>,
+        mint_seed: u64,
         name: String,
         symbol: String,
         uri: String,
         decimals: u8,
+        extensions: Vec<MintExtension>,
     ) -> Result<()> {
+        let is_token_2022 = ctx.accounts.token_program.key() == spl_token_2022::ID;
+        require!(
+            extensions.is_empty() || is_token_2022,
+            SSSOneError::ExtensionsRequireToken2022
+        );
+        StablecoinConfig::validate_metadata(&name, &symbol, &uri)?;
+        
         let config = &mut ctx.accounts.config;
         
         config.name = name.clone();
@@ -35,28 +52,177 @@ pub mod sss_one {
         config.uri = uri.clone();
         config.decimals = decimals;
         config.mint = ctx.accounts.mint.key();
+        config.bump = ctx.bumps.config;
+        config.mint_bump = ctx.bumps.mint;
+        config.mint_authority_bump = ctx.bumps.mint_authority;
+        config.permanent_delegate_bump = ctx.bumps.permanent_delegate;
         config.authority = ctx.accounts.authority.key();
-        config.minter = ctx.accounts.authority.key();
+        config.master_minter = ctx.accounts.authority.key();
         config.burner = ctx.accounts.authority.key();
         config.pauser = ctx.accounts.authority.key();
-        config.is_paused = false;
+        config.blacklister = ctx.accounts.authority.key();
+        config.guardian = ctx.accounts.authority.key();
+        config.freezer = ctx.accounts.authority.key();
+        config.kyc_officer = ctx.accounts.authority.key();
+        config.attestor = ctx.accounts.authority.key();
+        config.metadata_authority = ctx.accounts.authority.key();
+        config.pending_authority = None;
+        config.pending_master_minter = None;
+        config.pending_burner = None;
+        config.pending_pauser = None;
+        config.pending_blacklister = None;
+        config.pending_guardian = None;
+        config.pending_freezer = None;
+        config.pending_kyc_officer = None;
+        config.pending_attestor = None;
+        config.pending_metadata_authority = None;
+        config.multisig = None;
+        config.timelock_delay = None;
+        config.timelock_count = 0;
+        config.redemption_count = 0;
+        config.paused_operations = 0;
+        config.pause_on_supply_discrepancy = false;
+        config.redeem_requires_kyc = false;
         config.total_supply = 0;
+        config.max_supply = None;
+        config.mint_rate_limit = None;
+        config.reserve_policy = None;
+        config.version = StablecoinConfig::CURRENT_VERSION;
+        config.fee_admin = ctx.accounts.authority.key();
+        config.pending_fee_admin = None;
+        config.rate_authority = ctx.accounts.authority.key();
+        config.pending_rate_authority = None;
+        config.auditor_elgamal_pubkey = extensions.iter().find_map(|extension| match extension {
+            MintExtension::ConfidentialTransfer { auditor_elgamal_pubkey } => Some(*auditor_elgamal_pubkey),
+            _ => None,
+        });
+        if let Some(auditor_elgamal_pubkey) = config.auditor_elgamal_pubkey {
+            require!(auditor_elgamal_pubkey != [0; 32], SSSOneError::InvalidAuditorKey);
+        }
+        config.mint_seed = mint_seed;
+        config.reserved = [0; 128];
         config.initialized = true;
         
-        // Initialize mint authority
-        let cpi_accounts = anchor_spl::token::InitializeMint {
+        // Allocate the mint PDA with room for the requested extensions. On
+        // Token-2022 the mint also carries its own metadata, so fund the rent
+        // for it up front.
+        let space = extensions::mint_space(&extensions, is_token_2022)?;
+        let metadata_space = if is_token_2022 {
+            extensions::token_metadata_space(
+                ctx.accounts.mint.key,
+                ctx.accounts.mint_authority.key,
+                &name,
+                &symbol,
+                &uri,
+            )?
+        } else {
+            0
+        };
+        let lamports = Rent::get()?.minimum_balance(space + metadata_space);
+        let authority_key = ctx.accounts.authority.key();
+        let mint_seed_bytes = mint_seed.to_le_bytes();
+        let seeds: &[&[u8]] = &[
+            b"mint",
+            authority_key.as_ref(),
+            &mint_seed_bytes,
+            &[ctx.bumps.mint],
+        ];
+        let signer_seeds = &[seeds];
+        let cpi_program = ctx.accounts.system_program.to_account_info();
+        
+        // Fund, allocate and assign separately rather than `create_account`,
+        // which fails once anyone has sent lamports to the address
+        let shortfall = lamports.saturating_sub(ctx.accounts.mint.lamports());
+        if shortfall > 0 {
+            let cpi_accounts = system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.mint.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts);
+            system_program::transfer(cpi_ctx, shortfall)?;
+        }
+        let cpi_accounts = system_program::Allocate {
+            account_to_allocate: ctx.accounts.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer_seeds);
+        system_program::allocate(cpi_ctx, space as u64)?;
+        let cpi_accounts = system_program::Assign {
+            account_to_assign: ctx.accounts.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        system_program::assign(cpi_ctx, ctx.accounts.token_program.key)?;
+        
+        // Extensions must be initialized before the mint itself
+        for extension in extensions.iter() {
+            extensions::initialize_extension(
+                extension,
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.mint.to_account_info(),
+                ctx.accounts.mint_authority.key,
+                ctx.accounts.permanent_delegate.key,
+            )?;
+        }
+        if is_token_2022 {
+            extensions::initialize_metadata_pointer(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.mint.to_account_info(),
+                ctx.accounts.mint_authority.key,
+            )?;
+        }
+        
+        // Initialize mint with the PDA as mint and freeze authority
+        let cpi_accounts = token_interface::InitializeMint2 {
             mint: ctx.accounts.mint.to_account_info(),
-            rent: ctx.accounts.rent.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        anchor_spl::token::initialize_mint(
+        token_interface::initialize_mint2(
             cpi_ctx,
             decimals,
             &ctx.accounts.mint_authority.key(),
             Some(&ctx.accounts.mint_authority.key()), // freeze authority
         )?;
         
+        for extension in extensions.iter() {
+            match extension {
+                MintExtension::TransferFee { basis_points, max_fee } => {
+                    emit!(TransferFeeUpdated {
+                        basis_points: *basis_points,
+                        max_fee: *max_fee,
+                        effective_epoch: Clock::get()?.epoch,
+                        fee_admin: ctx.accounts.authority.key(),
+                    });
+                }
+                MintExtension::InterestBearing { rate } => {
+                    emit!(InterestRateUpdated {
+                        rate: *rate,
+                        effective_timestamp: Clock::get()?.unix_timestamp,
+                        rate_authority: ctx.accounts.authority.key(),
+                    });
+                }
+                _ => {}
+            }
+        }
+        
+        // Token metadata can only be written once the mint is initialized
+        if is_token_2022 {
+            let config_key = ctx.accounts.config.key();
+            let seeds: &[&[u8]] = &[
+                b"mint_authority",
+                config_key.as_ref(),
+                &[ctx.bumps.mint_authority],
+            ];
+            extensions::initialize_token_metadata(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.mint.to_account_info(),
+                &ctx.accounts.mint_authority.to_account_info(),
+                name.clone(),
+                symbol.clone(),
+                uri,
+                &[seeds],
+            )?;
+        }
+        
         emit!(StablecoinInitialized {
             name,
             symbol: symbol.clone(),
@@ -69,6 +235,160 @@ pub mod sss_one {
         Ok(())
     }
 
+    /// Update the name, symbol and URI in the config and, on Token-2022, in
+    /// the mint's metadata. Fields left as `None` are unchanged.
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let name = name.unwrap_or_else(|| config.name.clone());
+        let symbol = symbol.unwrap_or_else(|| config.symbol.clone());
+        let uri = uri.unwrap_or_else(|| config.uri.clone());
+        StablecoinConfig::validate_metadata(&name, &symbol, &uri)?;
+        
+        let mint_info = ctx.accounts.mint.to_account_info();
+        if let Some(metadata) = extensions::token_metadata(&mint_info)? {
+            let updates: Vec<(Field, String)> = [
+                (Field::Name, &metadata.name, &name),
+                (Field::Symbol, &metadata.symbol, &symbol),
+                (Field::Uri, &metadata.uri, &uri),
+            ]
+            .into_iter()
+            .filter(|(_, current, new)| current != new)
+            .map(|(field, _, new)| (field, new.clone()))
+            .collect();
+            
+            // Top up rent before Token-2022 reallocates the mint
+            let mut new_metadata = metadata.clone();
+            for (field, value) in updates.iter() {
+                new_metadata.update(field.clone(), value.clone());
+            }
+            let new_len = (mint_info.data_len() + new_metadata.tlv_size_of()?)
+                .saturating_sub(metadata.tlv_size_of()?);
+            let shortfall = Rent::get()?.minimum_balance(new_len)
+                .saturating_sub(mint_info.lamports());
+            if shortfall > 0 {
+                let cpi_accounts = system_program::Transfer {
+                    from: ctx.accounts.metadata_authority.to_account_info(),
+                    to: mint_info.clone(),
+                };
+                let cpi_program = ctx.accounts.system_program.to_account_info();
+                let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+                system_program::transfer(cpi_ctx, shortfall)?;
+            }
+            
+            let config_key = config.key();
+            let seeds: &[&[u8]] = &[
+                b"mint_authority",
+                config_key.as_ref(),
+                &[config.mint_authority_bump],
+            ];
+            for (field, value) in updates {
+                extensions::update_token_metadata_field(
+                    &ctx.accounts.token_program.to_account_info(),
+                    &mint_info,
+                    &ctx.accounts.mint_authority.to_account_info(),
+                    field,
+                    value,
+                    &[seeds],
+                )?;
+            }
+        }
+        
+        let config = &mut ctx.accounts.config;
+        config.name = name;
+        config.symbol = symbol;
+        config.uri = uri;
+        
+        emit!(MetadataUpdated {
+            name: config.name.clone(),
+            symbol: config.symbol.clone(),
+            uri: config.uri.clone(),
+            metadata_authority: ctx.accounts.metadata_authority.key(),
+        });
+        
+        msg!("Metadata updated: {}", config.symbol);
+        Ok(())
+    }
+
+    /// Move a version 0 config, created as a keypair account, to the config
+    /// PDA in the current layout. The mint and freeze authority pass to the
+    /// new config's mint authority PDA and the legacy account is closed.
+    pub fn migrate_config(
+        ctx: Context<MigrateConfig>,
+    ) -> Result<()> {
+        let legacy_info = ctx.accounts.legacy_config.to_account_info();
+        let legacy = migration::StablecoinConfigV0::try_from_account_data(
+            &legacy_info.try_borrow_data()?,
+        )?;
+        require!(
+            legacy.mint == ctx.accounts.mint.key(),
+            SSSOneError::ConfigMintMismatch
+        );
+        require!(
+            legacy.authority == ctx.accounts.authority.key(),
+            SSSOneError::UnauthorizedAuthority
+        );
+        
+        // Version 0 mints are keypair accounts, so there is no mint bump
+        let mut config = migration::upgrade_config(legacy)?;
+        config.bump = ctx.bumps.config;
+        config.mint_authority_bump = ctx.bumps.mint_authority;
+        config.permanent_delegate_bump = ctx.bumps.permanent_delegate;
+        ctx.accounts.config.set_inner(config);
+        
+        // Hand the mint and freeze authority to the new mint authority PDA
+        let legacy_key = legacy_info.key();
+        let seeds: &[&[u8]] = &[
+            b"mint_authority",
+            legacy_key.as_ref(),
+            &[ctx.bumps.legacy_mint_authority],
+        ];
+        let signer_seeds = &[seeds];
+        let legacy_mint_authority = ctx.accounts.legacy_mint_authority.key();
+        let mut authority_types = vec![AuthorityType::MintTokens];
+        if Option::<Pubkey>::from(ctx.accounts.mint.freeze_authority) == Some(legacy_mint_authority) {
+            authority_types.push(AuthorityType::FreezeAccount);
+        }
+        for authority_type in authority_types {
+            let cpi_accounts = token_interface::SetAuthority {
+                current_authority: ctx.accounts.legacy_mint_authority.to_account_info(),
+                account_or_mint: ctx.accounts.mint.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token_interface::set_authority(
+                cpi_ctx,
+                authority_type,
+                Some(ctx.accounts.mint_authority.key()),
+            )?;
+        }
+        
+        // Close the legacy account, returning its rent to the authority
+        let authority_info = ctx.accounts.authority.to_account_info();
+        let lamports = legacy_info.lamports();
+        **authority_info.try_borrow_mut_lamports()? = authority_info.lamports()
+            .checked_add(lamports)
+            .ok_or(SSSOneError::Overflow)?;
+        **legacy_info.try_borrow_mut_lamports()? = 0;
+        legacy_info.realloc(0, false)?;
+        legacy_info.assign(&system_program::ID);
+        
+        let config = &ctx.accounts.config;
+        emit!(ConfigMigrated {
+            legacy_config: legacy_key,
+            config: config.key(),
+            from_version: 0,
+            to_version: config.version,
+        });
+        
+        msg!("Config {} migrated to {}", legacy_key, config.key());
+        Ok(())
+    }
+
     // ========================================================================
     // MINT OPERATIONS
     // ========================================================================
@@ -77,48 +397,152 @@ pub mod sss_one {
         ctx: Context<MintOperation>,
         amount: u64,
     ) -> Result<()> {
-        let config = &ctx.accounts.config;
+        ctx.accounts.mint_tokens(amount, None)
+    }
+
+    /// Mint tokens once per off-chain reference. A retry with the same
+    /// reference fails because its receipt already exists.
+    pub fn mint_with_reference(
+        ctx: Context<MintWithReference>,
+        amount: u64,
+        reference: [u8; 32],
+    ) -> Result<()> {
+        let operation = &ctx.accounts.mint_operation;
+        let receipt = &mut ctx.accounts.mint_receipt;
+        receipt.config = operation.config.key();
+        receipt.reference = reference;
+        receipt.minter = operation.minter.key();
+        receipt.recipient = operation.recipient.key();
+        receipt.amount = amount;
+        receipt.slot = Clock::get()?.slot;
+        receipt.bump = ctx.bumps.mint_receipt;
         
-        // Checks
-        require!(!config.is_paused, SSSOneError::ProgramPaused);
-        require!(config.initialized, SSSOneError::NotInitialized);
-        require!(
-            ctx.accounts.minter.key() == config.minter,
-            SSSOneError::UnauthorizedMinter
-        );
+        ctx.accounts.mint_operation.mint_tokens(amount, Some(reference))
+    }
+
+    /// Mint to several recipients at once. Recipient token accounts follow
+    /// as remaining accounts, one per amount.
+    pub fn batch_mint<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchMint<'info>>,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+        ctx.accounts.batch_mint_tokens(ctx.remaining_accounts, &amounts)
+    }
+
+    // ========================================================================
+    // MINTER MANAGEMENT
+    // ========================================================================
+    /// Add a minter or overwrite an existing minter's allowance
+    pub fn configure_minter(
+        ctx: Context<ConfigureMinter>,
+        allowance: u64,
+    ) -> Result<()> {
+        let minter_info = &mut ctx.accounts.minter_info;
+        let previous_allowance = minter_info.allowance;
         
-        // Mint tokens
-        let cpi_accounts = anchor_spl::token::MintTo {
-            mint: ctx.accounts.mint.to_account_info(),
-            to: ctx.accounts.recipient_token_account.to_account_info(),
-            authority: ctx.accounts.mint_authority.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        anchor_spl::token::mint_to(cpi_ctx, amount)?;
+        minter_info.config = ctx.accounts.config.key();
+        minter_info.minter = ctx.accounts.minter.key();
+        minter_info.allowance = allowance;
+        minter_info.bump = ctx.bumps.minter_info;
         
-        // Update total supply
-        let config = &mut ctx.accounts.config;
-        config.total_supply = config.total_supply.checked_add(amount)
+        emit!(MinterConfigured {
+            minter: minter_info.minter,
+            previous_allowance,
+            new_allowance: allowance,
+        });
+        
+        msg!("Minter {} configured with allowance {}", minter_info.minter, allowance);
+        Ok(())
+    }
+
+    /// Increase an existing minter's allowance
+    pub fn increase_allowance(
+        ctx: Context<MinterManagement>,
+        increment: u64,
+    ) -> Result<()> {
+        require!(increment > 0, SSSOneError::InvalidAmount);
+        
+        let minter_info = &mut ctx.accounts.minter_info;
+        let previous_allowance = minter_info.allowance;
+        minter_info.allowance = previous_allowance.checked_add(increment)
             .ok_or(SSSOneError::Overflow)?;
         
-        emit!(TokensMinted {
-            recipient: ctx.accounts.recipient.key(),
-            amount,
-            new_supply: config.total_supply,
+        emit!(MinterAllowanceIncreased {
+            minter: minter_info.minter,
+            previous_allowance,
+            new_allowance: minter_info.allowance,
         });
-        
-        msg!("Minted {} tokens to {}", amount, ctx.accounts.recipient.key());
         Ok(())
     }
 
-    /// Set new minter
-    pub fn set_minter(
+    /// Set or clear the config-wide mint rate limit
+    pub fn set_mint_rate_limit(
         ctx: Context<AuthorityManagement>,
-        new_minter: Pubkey,
+        max_amount: Option<u64>,
+        window_seconds: i64,
+    ) -> Result<()> {
+        ctx.accounts.apply(
+            AdminAction::SetMintRateLimit {
+                max_amount,
+                window_seconds,
+            },
+            ctx.bumps.timelock_operation,
+        )
+    }
+
+    /// Set or clear the supply cap. A cap below the current supply blocks
+    /// further minting until burns bring supply back under it.
+    pub fn set_supply_cap(
+        ctx: Context<AuthorityManagement>,
+        max_supply: Option<u64>,
+    ) -> Result<()> {
+        ctx.accounts.apply(
+            AdminAction::SetSupplyCap { max_supply },
+            ctx.bumps.timelock_operation,
+        )
+    }
+
+    /// Set or clear a single minter's rate limit
+    pub fn set_minter_rate_limit(
+        ctx: Context<MinterRateLimitManagement>,
+        max_amount: Option<u64>,
+        window_seconds: i64,
     ) -> Result<()> {
-        ctx.accounts.config.minter = new_minter;
-        emit!(MinterUpdated { new_minter });
+        admin::authorize_action(
+            &ctx.accounts.config,
+            ctx.accounts.authority.key(),
+            ctx.accounts.multisig.as_ref(),
+            ctx.accounts.proposal.as_mut(),
+            AdminAction::SetMinterRateLimit {
+                minter: ctx.accounts.minter.key(),
+                max_amount,
+                window_seconds,
+            },
+        )?;
+        
+        ctx.accounts.minter_info.rate_limit = max_amount
+            .map(|max_amount| RateLimit::new(max_amount, window_seconds))
+            .transpose()?;
+        
+        emit!(MintRateLimitUpdated {
+            minter: Some(ctx.accounts.minter.key()),
+            max_amount,
+            window_seconds,
+        });
+        Ok(())
+    }
+
+    /// Remove a minter, closing its allowance account
+    pub fn remove_minter(
+        ctx: Context<RemoveMinter>,
+    ) -> Result<()> {
+        emit!(MinterRemoved {
+            minter: ctx.accounts.minter.key(),
+            previous_allowance: ctx.accounts.minter_info.allowance,
+            new_allowance: 0,
+        });
+        
+        msg!("Minter removed: {}", ctx.accounts.minter.key());
         Ok(())
     }
 
@@ -132,7 +556,7 @@ pub mod sss_one {
     ) -> Result<()> {
         let config = &ctx.accounts.config;
         
-        require!(!config.is_paused, SSSOneError::ProgramPaused);
+        config.require_not_paused(PauseFlags::BURN)?;
         require!(config.initialized, SSSOneError::NotInitialized);
         require!(
             ctx.accounts.burner.key() == config.burner,
@@ -140,14 +564,14 @@ pub mod sss_one {
         );
         
         // Burn tokens
-        let cpi_accounts = anchor_spl::token::Burn {
+        let cpi_accounts = token_interface::Burn {
             mint: ctx.accounts.mint.to_account_info(),
             from: ctx.accounts.token_account.to_account_info(),
             authority: ctx.accounts.burner.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        anchor_spl::token::burn(cpi_ctx, amount)?;
+        token_interface::burn(cpi_ctx, amount)?;
         
         // Update total supply
         let config = &mut ctx.accounts.config;
@@ -164,34 +588,737 @@ pub mod sss_one {
         Ok(())
     }
 
-    /// Set new burner
-    pub fn set_burner(
+    // ========================================================================
+    // REDEMPTION
+    // ========================================================================
+    /// Burn the holder's own tokens against an off-chain payout
+    pub fn redeem(
+        ctx: Context<Redeem>,
+        amount: u64,
+        payout_reference: [u8; 32],
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+        
+        config.require_not_paused(PauseFlags::REDEEM)?;
+        require!(config.initialized, SSSOneError::NotInitialized);
+        require!(amount > 0, SSSOneError::InvalidAmount);
+        config.check_redeem_kyc(
+            ctx.accounts.kyc_record.as_deref(),
+            Clock::get()?.unix_timestamp,
+        )?;
+        
+        // Burn tokens, signed by the holder
+        let cpi_accounts = token_interface::Burn {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.token_account.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token_interface::burn(cpi_ctx, amount)?;
+        
+        // Update total supply
+        let config = &mut ctx.accounts.config;
+        config.total_supply = config.total_supply.checked_sub(amount)
+            .ok_or(SSSOneError::Underflow)?;
+        
+        emit!(RedemptionRequested {
+            holder: ctx.accounts.holder.key(),
+            token_account: ctx.accounts.token_account.key(),
+            amount,
+            payout_reference,
+            new_supply: config.total_supply,
+        });
+        
+        msg!("Redeemed {} tokens", amount);
+        Ok(())
+    }
+
+    /// Escrow the holder's tokens and open a ticket for the burner to
+    /// settle once the payout has been sent
+    pub fn request_redemption<'info>(
+        ctx: Context<'_, '_, '_, 'info, RequestRedemption<'info>>,
+        amount: u64,
+        payout_reference: [u8; 32],
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let now = Clock::get()?.unix_timestamp;
+        
+        config.require_not_paused(PauseFlags::REDEEM)?;
+        require!(config.initialized, SSSOneError::NotInitialized);
+        require!(amount > 0, SSSOneError::InvalidAmount);
+        config.check_redeem_kyc(ctx.accounts.kyc_record.as_deref(), now)?;
+        
+        // Allowlist mints create the escrow frozen
+        if ctx.accounts.escrow_token_account.is_frozen() {
+            let config_key = ctx.accounts.config.key();
+            let seeds: &[&[u8]] = &[
+                b"mint_authority",
+                config_key.as_ref(),
+                &[ctx.accounts.config.mint_authority_bump],
+            ];
+            let signer_seeds = &[seeds];
+            let cpi_accounts = token_interface::ThawAccount {
+                account: ctx.accounts.escrow_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token_interface::thaw_account(cpi_ctx)?;
+        }
+        
+        // Move tokens into escrow, forwarding any hook accounts
+        spl_token_2022::onchain::invoke_transfer_checked(
+            ctx.accounts.token_program.key,
+            ctx.accounts.token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.escrow_token_account.to_account_info(),
+            ctx.accounts.holder.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[],
+        )?;
+        
+        // Escrow what actually arrived, net of any transfer fee
+        ctx.accounts.escrow_token_account.reload()?;
+        let amount = ctx.accounts.escrow_token_account.amount;
+        
+        let config = &mut ctx.accounts.config;
+        let ticket = &mut ctx.accounts.redemption_ticket;
+        ticket.config = config.key();
+        ticket.index = config.redemption_count;
+        ticket.holder = ctx.accounts.holder.key();
+        ticket.token_account = ctx.accounts.token_account.key();
+        ticket.amount = amount;
+        ticket.payout_reference = payout_reference;
+        ticket.status = RedemptionStatus::Pending;
+        ticket.created_at = now;
+        ticket.settled_at = None;
+        ticket.bump = ctx.bumps.redemption_ticket;
+        config.redemption_count = config.redemption_count.checked_add(1)
+            .ok_or(SSSOneError::Overflow)?;
+        
+        emit!(RedemptionTicketCreated {
+            ticket: ticket.key(),
+            holder: ticket.holder,
+            amount,
+            payout_reference,
+        });
+        
+        msg!("Redemption ticket {} opened for {} tokens", ticket.index, amount);
+        Ok(())
+    }
+
+    /// Burn escrowed tokens once the payout has gone out
+    pub fn settle_redemption(
+        ctx: Context<SettleRedemption>,
+    ) -> Result<()> {
+        ctx.accounts.config.require_not_paused(PauseFlags::REDEEM)?;
+        require!(
+            ctx.accounts.redemption_ticket.status == RedemptionStatus::Pending,
+            SSSOneError::RedemptionNotPending
+        );
+        
+        let ticket = &ctx.accounts.redemption_ticket;
+        let config_key = ctx.accounts.config.key();
+        let index = ticket.index.to_le_bytes();
+        let seeds: &[&[u8]] = &[
+            b"redemption",
+            config_key.as_ref(),
+            &index,
+            &[ticket.bump],
+        ];
+        let signer_seeds = &[seeds];
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        
+        // Burn from escrow, then return the escrow rent to the holder
+        let cpi_accounts = token_interface::Burn {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            authority: ticket.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer_seeds);
+        token_interface::burn(cpi_ctx, ticket.amount)?;
+        
+        // Token-2022 refuses to close an account holding withheld fees
+        let mint_info = ctx.accounts.mint.to_account_info();
+        if extensions::transfer_fee_config(&mint_info).is_ok() {
+            extensions::harvest_withheld_fees(
+                &ctx.accounts.token_program.to_account_info(),
+                &mint_info,
+                &[ctx.accounts.escrow_token_account.to_account_info()],
+            )?;
+        }
+        
+        let cpi_accounts = token_interface::CloseAccount {
+            account: ctx.accounts.escrow_token_account.to_account_info(),
+            destination: ctx.accounts.rent_receiver.to_account_info(),
+            authority: ticket.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token_interface::close_account(cpi_ctx)?;
+        
+        let config = &mut ctx.accounts.config;
+        let ticket = &mut ctx.accounts.redemption_ticket;
+        config.total_supply = config.total_supply.checked_sub(ticket.amount)
+            .ok_or(SSSOneError::Underflow)?;
+        ticket.status = RedemptionStatus::Settled;
+        ticket.settled_at = Some(Clock::get()?.unix_timestamp);
+        
+        emit!(RedemptionSettled {
+            ticket: ticket.key(),
+            holder: ticket.holder,
+            amount: ticket.amount,
+            payout_reference: ticket.payout_reference,
+            new_supply: config.total_supply,
+        });
+        
+        msg!("Redemption ticket {} settled", ticket.index);
+        Ok(())
+    }
+
+    /// Refund escrowed tokens to the holder without burning. On mints with a
+    /// transfer fee the refund is a regular transfer, so the holder pays the
+    /// fee a second time and receives less than the ticket amount.
+    pub fn reject_redemption<'info>(
+        ctx: Context<'_, '_, '_, 'info, RejectRedemption<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.config.require_not_paused(PauseFlags::REDEEM)?;
+        require!(
+            ctx.accounts.redemption_ticket.status == RedemptionStatus::Pending,
+            SSSOneError::RedemptionNotPending
+        );
+        
+        let ticket = &ctx.accounts.redemption_ticket;
+        let config_key = ctx.accounts.config.key();
+        let index = ticket.index.to_le_bytes();
+        let seeds: &[&[u8]] = &[
+            b"redemption",
+            config_key.as_ref(),
+            &index,
+            &[ticket.bump],
+        ];
+        let signer_seeds = &[seeds];
+        
+        // Refund from escrow, forwarding any hook accounts
+        spl_token_2022::onchain::invoke_transfer_checked(
+            ctx.accounts.token_program.key,
+            ctx.accounts.escrow_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.token_account.to_account_info(),
+            ticket.to_account_info(),
+            ctx.remaining_accounts,
+            ticket.amount,
+            ctx.accounts.mint.decimals,
+            signer_seeds,
+        )?;
+        
+        // Token-2022 refuses to close an account holding withheld fees
+        let mint_info = ctx.accounts.mint.to_account_info();
+        if extensions::transfer_fee_config(&mint_info).is_ok() {
+            extensions::harvest_withheld_fees(
+                &ctx.accounts.token_program.to_account_info(),
+                &mint_info,
+                &[ctx.accounts.escrow_token_account.to_account_info()],
+            )?;
+        }
+        
+        let cpi_accounts = token_interface::CloseAccount {
+            account: ctx.accounts.escrow_token_account.to_account_info(),
+            destination: ctx.accounts.rent_receiver.to_account_info(),
+            authority: ticket.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token_interface::close_account(cpi_ctx)?;
+        
+        let ticket = &mut ctx.accounts.redemption_ticket;
+        ticket.status = RedemptionStatus::Rejected;
+        ticket.settled_at = Some(Clock::get()?.unix_timestamp);
+        
+        emit!(RedemptionRejected {
+            ticket: ticket.key(),
+            holder: ticket.holder,
+            amount: ticket.amount,
+            payout_reference: ticket.payout_reference,
+        });
+        
+        msg!("Redemption ticket {} rejected", ticket.index);
+        Ok(())
+    }
+
+    /// Limit holder redemptions to KYC-approved holders, or open them to everyone
+    pub fn set_redeem_requires_kyc(
+        ctx: Context<AuthorityManagement>,
+        enabled: bool,
+    ) -> Result<()> {
+        ctx.accounts.apply(
+            AdminAction::SetRedeemRequiresKyc { enabled },
+            ctx.bumps.timelock_operation,
+        )
+    }
+
+    // ========================================================================
+    // SUPPLY RECONCILIATION
+    // ========================================================================
+    /// Compare the tracked total supply with the mint's actual supply.
+    /// Permissionless, so anyone can audit the reported figure.
+    pub fn reconcile_supply(ctx: Context<ReconcileSupply>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let mint_supply = ctx.accounts.mint.supply;
+        
+        if config.total_supply == mint_supply {
+            msg!("Supply reconciled: {}", mint_supply);
+            return Ok(());
+        }
+        
+        // Tokens minted outside the program halt supply changes until the
+        // discrepancy is explained. A mint supply below the tracked figure
+        // only means tokens were burned directly, which `sync_supply` records.
+        let supply_flags = PauseFlags::MINT | PauseFlags::BURN | PauseFlags::REDEEM;
+        let auto_paused = config.pause_on_supply_discrepancy
+            && mint_supply > config.total_supply
+            && config.paused_operations & supply_flags != supply_flags;
+        if auto_paused {
+            config.pause_operations(supply_flags)?;
+        }
+        
+        emit!(SupplyDiscrepancy {
+            config_supply: config.total_supply,
+            mint_supply,
+            auto_paused,
+        });
+        
+        msg!("Supply discrepancy: config {} vs mint {}", config.total_supply, mint_supply);
+        Ok(())
+    }
+
+    /// Set the tracked total supply to the mint's actual supply, e.g. after
+    /// holders burned tokens directly through the token program
+    pub fn sync_supply(ctx: Context<SyncSupply>) -> Result<()> {
+        let mint_supply = ctx.accounts.mint.supply;
+        let authority = admin::authorize_action(
+            &ctx.accounts.config,
+            ctx.accounts.authority.key(),
+            ctx.accounts.multisig.as_ref(),
+            ctx.accounts.proposal.as_mut(),
+            AdminAction::SyncSupply { mint_supply },
+        )?;
+        
+        let config = &mut ctx.accounts.config;
+        let previous_supply = config.total_supply;
+        config.total_supply = mint_supply;
+        
+        emit!(SupplySynced {
+            previous_supply,
+            new_supply: mint_supply,
+            authority,
+        });
+        
+        msg!("Supply synced: {} -> {}", previous_supply, mint_supply);
+        Ok(())
+    }
+
+    /// Enable or disable pausing when `reconcile_supply` finds unbacked tokens
+    pub fn set_pause_on_supply_discrepancy(
+        ctx: Context<AuthorityManagement>,
+        enabled: bool,
+    ) -> Result<()> {
+        ctx.accounts.apply(
+            AdminAction::SetPauseOnSupplyDiscrepancy { enabled },
+            ctx.bumps.timelock_operation,
+        )
+    }
+
+    // ========================================================================
+    // PROOF OF RESERVE
+    // ========================================================================
+    /// Publish the latest reserve report. Timestamps may not go backwards
+    /// or lie in the future.
+    pub fn attest_reserves(
+        ctx: Context<AttestReserves>,
+        total_reserves: u64,
+        timestamp: i64,
+        report_hash: [u8; 32],
+    ) -> Result<()> {
+        let attestation = &mut ctx.accounts.reserve_attestation;
+        
+        require!(
+            timestamp <= Clock::get()?.unix_timestamp && timestamp >= attestation.timestamp,
+            SSSOneError::InvalidAttestationTimestamp
+        );
+        
+        attestation.config = ctx.accounts.config.key();
+        attestation.total_reserves = total_reserves;
+        attestation.timestamp = timestamp;
+        attestation.report_hash = report_hash;
+        attestation.attestor = ctx.accounts.attestor.key();
+        attestation.bump = ctx.bumps.reserve_attestation;
+        
+        emit!(ReservesAttested {
+            total_reserves,
+            timestamp,
+            report_hash,
+            attestor: attestation.attestor,
+        });
+        
+        msg!("Reserves attested: {}", total_reserves);
+        Ok(())
+    }
+
+    /// Set or clear the reserve policy enforced by `mint`
+    pub fn set_reserve_policy(
         ctx: Context<AuthorityManagement>,
-        new_burner: Pubkey,
+        policy: Option<ReservePolicy>,
+    ) -> Result<()> {
+        ctx.accounts.apply(
+            AdminAction::SetReservePolicy { policy },
+            ctx.bumps.timelock_operation,
+        )
+    }
+
+    // ========================================================================
+    // TRANSFER FEES
+    // ========================================================================
+    /// Schedule a new transfer fee. Token-2022 applies it two epochs later.
+    pub fn set_transfer_fee(
+        ctx: Context<TransferFeeManagement>,
+        basis_points: u16,
+        max_fee: u64,
+    ) -> Result<()> {
+        require!(
+            basis_points <= spl_token_2022::extension::transfer_fee::MAX_FEE_BASIS_POINTS,
+            SSSOneError::InvalidTransferFee
+        );
+        
+        let mint_info = ctx.accounts.mint.to_account_info();
+        extensions::transfer_fee_config(&mint_info)?;
+        
+        let config_key = ctx.accounts.config.key();
+        let seeds: &[&[u8]] = &[
+            b"mint_authority",
+            config_key.as_ref(),
+            &[ctx.accounts.config.mint_authority_bump],
+        ];
+        extensions::set_transfer_fee(
+            &ctx.accounts.token_program.to_account_info(),
+            &mint_info,
+            &ctx.accounts.mint_authority.to_account_info(),
+            basis_points,
+            max_fee,
+            &[seeds],
+        )?;
+        
+        let fee_config = extensions::transfer_fee_config(&mint_info)?;
+        emit!(TransferFeeUpdated {
+            basis_points,
+            max_fee,
+            effective_epoch: u64::from(fee_config.newer_transfer_fee.epoch),
+            fee_admin: ctx.accounts.fee_admin.key(),
+        });
+        
+        msg!("Transfer fee set to {} bps, max {}", basis_points, max_fee);
+        Ok(())
+    }
+
+    /// Harvest fees withheld in the remaining token accounts into the mint
+    pub fn harvest_transfer_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, HarvestTransferFees<'info>>,
+    ) -> Result<()> {
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let withheld_before = u64::from(extensions::transfer_fee_config(&mint_info)?.withheld_amount);
+        
+        extensions::harvest_withheld_fees(
+            &ctx.accounts.token_program.to_account_info(),
+            &mint_info,
+            ctx.remaining_accounts,
+        )?;
+        
+        let withheld_after = u64::from(extensions::transfer_fee_config(&mint_info)?.withheld_amount);
+        let amount = withheld_after.checked_sub(withheld_before)
+            .ok_or(SSSOneError::Underflow)?;
+        
+        emit!(TransferFeesHarvested {
+            sources: ctx.remaining_accounts.len() as u32,
+            amount,
+        });
+        
+        msg!("Harvested {} in transfer fees", amount);
+        Ok(())
+    }
+
+    /// Withdraw all fees withheld in the mint to a treasury token account
+    pub fn withdraw_transfer_fees(
+        ctx: Context<WithdrawTransferFees>,
+    ) -> Result<()> {
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let amount = u64::from(extensions::transfer_fee_config(&mint_info)?.withheld_amount);
+        require!(amount > 0, SSSOneError::InvalidAmount);
+        
+        let config_key = ctx.accounts.config.key();
+        let seeds: &[&[u8]] = &[
+            b"mint_authority",
+            config_key.as_ref(),
+            &[ctx.accounts.config.mint_authority_bump],
+        ];
+        extensions::withdraw_withheld_fees(
+            &ctx.accounts.token_program.to_account_info(),
+            &mint_info,
+            &ctx.accounts.treasury_token_account.to_account_info(),
+            &ctx.accounts.mint_authority.to_account_info(),
+            &[seeds],
+        )?;
+        
+        emit!(TransferFeesWithdrawn {
+            treasury: ctx.accounts.treasury_token_account.key(),
+            amount,
+            fee_admin: ctx.accounts.fee_admin.key(),
+        });
+        
+        msg!("Withdrew {} in transfer fees", amount);
+        Ok(())
+    }
+
+    // ========================================================================
+    // INTEREST
+    // ========================================================================
+    /// Update the interest rate, in basis points. Interest accrued so far
+    /// is kept and the new rate applies from now on.
+    pub fn update_interest_rate(
+        ctx: Context<InterestRateManagement>,
+        rate: i16,
+    ) -> Result<()> {
+        let mint_info = ctx.accounts.mint.to_account_info();
+        extensions::interest_bearing_config(&mint_info)?;
+        
+        let config_key = ctx.accounts.config.key();
+        let seeds: &[&[u8]] = &[
+            b"mint_authority",
+            config_key.as_ref(),
+            &[ctx.accounts.config.mint_authority_bump],
+        ];
+        extensions::update_interest_rate(
+            &ctx.accounts.token_program.to_account_info(),
+            &mint_info,
+            &ctx.accounts.mint_authority.to_account_info(),
+            rate,
+            &[seeds],
+        )?;
+        
+        let interest_config = extensions::interest_bearing_config(&mint_info)?;
+        emit!(InterestRateUpdated {
+            rate,
+            effective_timestamp: i64::from(interest_config.last_update_timestamp),
+            rate_authority: ctx.accounts.rate_authority.key(),
+        });
+        
+        msg!("Interest rate set to {} bps", rate);
+        Ok(())
+    }
+
+    // ========================================================================
+    // CONFIDENTIAL TRANSFERS
+    // ========================================================================
+    /// Approve a token account configured for confidential transfers
+    pub fn approve_confidential_account(
+        ctx: Context<ApproveConfidentialAccount>,
     ) -> Result<()> {
-        ctx.accounts.config.burner = new_burner;
-        emit!(BurnerUpdated { new_burner });
+        let authority = admin::authorize_action(
+            &ctx.accounts.config,
+            ctx.accounts.authority.key(),
+            ctx.accounts.multisig.as_ref(),
+            ctx.accounts.proposal.as_mut(),
+            AdminAction::ApproveConfidentialAccount {
+                token_account: ctx.accounts.token_account.key(),
+            },
+        )?;
+        
+        let config_key = ctx.accounts.config.key();
+        let seeds: &[&[u8]] = &[
+            b"mint_authority",
+            config_key.as_ref(),
+            &[ctx.accounts.config.mint_authority_bump],
+        ];
+        extensions::approve_confidential_account(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.token_account.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.mint_authority.to_account_info(),
+            &[seeds],
+        )?;
+        
+        emit!(ConfidentialAccountApproved {
+            token_account: ctx.accounts.token_account.key(),
+            authority,
+        });
+        
+        msg!("Confidential account approved: {}", ctx.accounts.token_account.key());
+        Ok(())
+    }
+
+    /// Rotate the auditor ElGamal key in the config and on the mint
+    pub fn rotate_auditor_key(
+        ctx: Context<RotateAuditorKey>,
+        auditor_elgamal_pubkey: [u8; 32],
+    ) -> Result<()> {
+        require!(auditor_elgamal_pubkey != [0; 32], SSSOneError::InvalidAuditorKey);
+        let authority = admin::authorize_action(
+            &ctx.accounts.config,
+            ctx.accounts.authority.key(),
+            ctx.accounts.multisig.as_ref(),
+            ctx.accounts.proposal.as_mut(),
+            AdminAction::RotateAuditorKey { auditor_elgamal_pubkey },
+        )?;
+        
+        let config_key = ctx.accounts.config.key();
+        let seeds: &[&[u8]] = &[
+            b"mint_authority",
+            config_key.as_ref(),
+            &[ctx.accounts.config.mint_authority_bump],
+        ];
+        extensions::update_auditor_elgamal_pubkey(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.mint_authority.to_account_info(),
+            auditor_elgamal_pubkey,
+            &[seeds],
+        )?;
+        
+        let config = &mut ctx.accounts.config;
+        let previous_auditor_elgamal_pubkey = config.auditor_elgamal_pubkey;
+        config.auditor_elgamal_pubkey = Some(auditor_elgamal_pubkey);
+        
+        emit!(AuditorKeyRotated {
+            previous_auditor_elgamal_pubkey,
+            new_auditor_elgamal_pubkey: auditor_elgamal_pubkey,
+            authority,
+        });
+        
+        msg!("Auditor key rotated");
+        Ok(())
+    }
+
+    // ========================================================================
+    // SEIZE OPERATIONS
+    // ========================================================================
+    /// Move tokens out of a blacklisted account using the permanent delegate.
+    /// Seizes the full balance when `amount` is `None`.
+    pub fn seize<'info>(
+        ctx: Context<'_, '_, '_, 'info, Seize<'info>>,
+        amount: Option<u64>,
+    ) -> Result<()> {
+        admin::authorize_action(
+            &ctx.accounts.config,
+            ctx.accounts.authority.key(),
+            ctx.accounts.multisig.as_ref(),
+            ctx.accounts.proposal.as_mut(),
+            AdminAction::Seize {
+                source_token_account: ctx.accounts.source_token_account.key(),
+                treasury_token_account: ctx.accounts.treasury_token_account.key(),
+                amount,
+            },
+        )?;
+        let config = &ctx.accounts.config;
+        
+        config.require_not_paused(PauseFlags::SEIZE)?;
+        require!(config.initialized, SSSOneError::NotInitialized);
+        require!(
+            extensions::permanent_delegate(&ctx.accounts.mint.to_account_info())?
+                == Some(ctx.accounts.permanent_delegate.key()),
+            SSSOneError::PermanentDelegateNotEnabled
+        );
+        
+        let balance = ctx.accounts.source_token_account.amount;
+        let amount = amount.unwrap_or(balance);
+        require!(amount > 0, SSSOneError::InvalidAmount);
+        require!(amount <= balance, SSSOneError::InsufficientBalance);
+        
+        // Token-2022 refuses transfers out of a frozen account, even by the
+        // permanent delegate, so thaw it for the seizure
+        let config_key = config.key();
+        let mint_authority_seeds: &[&[u8]] = &[
+            b"mint_authority",
+            config_key.as_ref(),
+            &[config.mint_authority_bump],
+        ];
+        let mint_authority_signer = &[mint_authority_seeds];
+        let frozen = ctx.accounts.source_token_account.is_frozen();
+        if frozen {
+            let cpi_accounts = token_interface::ThawAccount {
+                account: ctx.accounts.source_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, mint_authority_signer);
+            token_interface::thaw_account(cpi_ctx)?;
+        }
+        
+        // Transfer as the permanent delegate, forwarding any hook accounts
+        let seeds: &[&[u8]] = &[
+            b"permanent_delegate",
+            config_key.as_ref(),
+            &[config.permanent_delegate_bump],
+        ];
+        spl_token_2022::onchain::invoke_transfer_checked(
+            ctx.accounts.token_program.key,
+            ctx.accounts.source_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.treasury_token_account.to_account_info(),
+            ctx.accounts.permanent_delegate.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[seeds],
+        )?;
+        
+        if frozen {
+            let cpi_accounts = token_interface::FreezeAccount {
+                account: ctx.accounts.source_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, mint_authority_signer);
+            token_interface::freeze_account(cpi_ctx)?;
+        }
+        
+        emit!(TokensSeized {
+            from: ctx.accounts.source_token_account.key(),
+            to: ctx.accounts.treasury_token_account.key(),
+            amount,
+            case_reference: ctx.accounts.blacklist_entry.case_reference,
+        });
+        
+        msg!("Seized {} tokens from {}", amount, ctx.accounts.source_token_account.key());
         Ok(())
     }
 
     // ========================================================================
     // FREEZE/THAW OPERATIONS
     // ========================================================================
-    /// Freeze a token account
+    /// Freeze a token account. Never paused, so compliance can keep
+    /// acting while the config is paused.
     pub fn freeze_account(
         ctx: Context<FreezeThaw>,
     ) -> Result<()> {
-        let config = &ctx.accounts.config;
-        require!(!config.is_paused, SSSOneError::ProgramPaused);
-        
-        let cpi_accounts = anchor_spl::token::FreezeAccount {
+        let config_key = ctx.accounts.config.key();
+        let seeds: &[&[u8]] = &[
+            b"mint_authority",
+            config_key.as_ref(),
+            &[ctx.accounts.config.mint_authority_bump],
+        ];
+        let signer_seeds = &[seeds];
+        let cpi_accounts = token_interface::FreezeAccount {
             account: ctx.accounts.token_account.to_account_info(),
             mint: ctx.accounts.mint.to_account_info(),
-            authority: ctx.accounts.freezer.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        anchor_spl::token::freeze_account(cpi_ctx)?;
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token_interface::freeze_account(cpi_ctx)?;
         
         emit!(AccountFrozen {
             account: ctx.accounts.token_account.key(),
@@ -206,16 +1333,23 @@ pub mod sss_one {
         ctx: Context<FreezeThaw>,
     ) -> Result<()> {
         let config = &ctx.accounts.config;
-        require!(!config.is_paused, SSSOneError::ProgramPaused);
+        config.require_not_paused(PauseFlags::THAW)?;
         
-        let cpi_accounts = anchor_spl::token::ThawAccount {
+        let config_key = config.key();
+        let seeds: &[&[u8]] = &[
+            b"mint_authority",
+            config_key.as_ref(),
+            &[config.mint_authority_bump],
+        ];
+        let signer_seeds = &[seeds];
+        let cpi_accounts = token_interface::ThawAccount {
             account: ctx.accounts.token_account.to_account_info(),
             mint: ctx.accounts.mint.to_account_info(),
-            authority: ctx.accounts.freezer.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        anchor_spl::token::thaw_account(cpi_ctx)?;
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token_interface::thaw_account(cpi_ctx)?;
         
         emit!(AccountThawed {
             account: ctx.accounts.token_account.key(),
@@ -230,45 +1364,428 @@ pub mod sss_one {
     // ========================================================================
     /// Pause all operations
     pub fn pause(ctx: Context<PauseManagement>) -> Result<()> {
-        let config = &mut ctx.accounts.config;
-        require!(!config.is_paused, SSSOneError::AlreadyPaused);
-        config.is_paused = true;
-        emit!(ProgramPaused { authority: ctx.accounts.pauser.key() });
-        msg!("Program paused by {}", ctx.accounts.pauser.key());
-        Ok(())
+        pause_operations(ctx, PauseFlags::ALL)
     }
 
-    /// Unpause operations
+    /// Unpause all paused operations. Requires a multisig proposal once one
+    /// is enabled.
     pub fn unpause(ctx: Context<PauseManagement>) -> Result<()> {
+        let mask = ctx.accounts.config.paused_operations;
+        require!(mask != 0, SSSOneError::NotPaused);
+        unpause_operations(ctx, mask)
+    }
+
+    /// Pause the operations in `mask`, a set of `PauseFlags`
+    pub fn pause_operations(ctx: Context<PauseManagement>, mask: u8) -> Result<()> {
         let config = &mut ctx.accounts.config;
-        require!(config.is_paused, SSSOneError::NotPaused);
-        config.is_paused = false;
-        emit!(ProgramUnpaused { authority: ctx.accounts.pauser.key() });
-        msg!("Program unpaused by {}", ctx.accounts.pauser.key());
+        let flags = config.pause_operations(mask)?;
+        emit!(OperationsPaused { flags, authority: ctx.accounts.pauser.key() });
+        msg!("Operations {:#010b} paused by {}", flags, ctx.accounts.pauser.key());
         Ok(())
     }
 
+    /// Unpause the operations in `mask`, a set of `PauseFlags`. Requires a
+    /// multisig proposal once one is enabled.
+    pub fn unpause_operations(ctx: Context<PauseManagement>, mask: u8) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(config.multisig.is_none(), SSSOneError::MultisigRequired);
+        AdminAction::UnpauseOperations { mask }.apply(config, ctx.accounts.pauser.key())
+    }
+
     // ========================================================================
     // AUTHORITY MANAGEMENT
     // ========================================================================
-    /// Transfer master authority
-    pub fn transfer_authority(
+    /// Propose a new master authority; takes effect once it accepts
+    pub fn propose_authority(
         ctx: Context<AuthorityManagement>,
         new_authority: Pubkey,
     ) -> Result<()> {
-        ctx.accounts.config.authority = new_authority;
+        ctx.accounts.apply(
+            AdminAction::ProposeAuthority { new_authority },
+            ctx.bumps.timelock_operation,
+        )
+    }
+
+    /// Accept a pending authority transfer, signed by the pending key
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let new_authority = ctx.accounts.pending_authority.key();
+        require!(
+            config.pending_authority == Some(new_authority),
+            SSSOneError::UnauthorizedPendingAuthority
+        );
+        
+        config.authority = new_authority;
+        config.pending_authority = None;
         emit!(AuthorityTransferred { new_authority });
         msg!("Authority transferred to {}", new_authority);
         Ok(())
     }
 
-    /// Set pauser
-    pub fn set_pauser(
+    /// Cancel a pending authority transfer
+    pub fn cancel_authority_transfer(ctx: Context<AuthorityManagement>) -> Result<()> {
+        ctx.accounts.apply(
+            AdminAction::CancelAuthorityTransfer,
+            ctx.bumps.timelock_operation,
+        )
+    }
+
+    /// Propose a new holder for a role; takes effect once it accepts
+    pub fn propose_role(
+        ctx: Context<AuthorityManagement>,
+        role: Role,
+        new_holder: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.apply(
+            AdminAction::ProposeRole { role, new_holder },
+            ctx.bumps.timelock_operation,
+        )
+    }
+
+    /// Accept a pending role transfer, signed by the pending key
+    pub fn accept_role(
+        ctx: Context<AcceptRole>,
+        role: Role,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let new_holder = ctx.accounts.pending_holder.key();
+        require!(
+            config.pending_role(role) == Some(new_holder),
+            SSSOneError::UnauthorizedPendingRole
+        );
+        
+        *config.role_mut(role) = new_holder;
+        *config.pending_role_mut(role) = None;
+        match role {
+            Role::MasterMinter => emit!(MasterMinterUpdated { new_master_minter: new_holder }),
+            Role::Burner => emit!(BurnerUpdated { new_burner: new_holder }),
+            Role::Pauser => emit!(PauserUpdated { new_pauser: new_holder }),
+            Role::Blacklister => emit!(BlacklisterUpdated { new_blacklister: new_holder }),
+            Role::Guardian => emit!(GuardianUpdated { new_guardian: new_holder }),
+            Role::Freezer => emit!(FreezerUpdated { new_freezer: new_holder }),
+            Role::KycOfficer => emit!(KycOfficerUpdated { new_kyc_officer: new_holder }),
+            Role::Attestor => emit!(AttestorUpdated { new_attestor: new_holder }),
+            Role::MetadataAuthority => emit!(MetadataAuthorityUpdated { new_metadata_authority: new_holder }),
+            Role::FeeAdmin => emit!(FeeAdminUpdated { new_fee_admin: new_holder }),
+            Role::RateAuthority => emit!(RateAuthorityUpdated { new_rate_authority: new_holder }),
+        }
+        Ok(())
+    }
+
+    /// Cancel a pending role transfer
+    pub fn cancel_role_transfer(
+        ctx: Context<AuthorityManagement>,
+        role: Role,
+    ) -> Result<()> {
+        ctx.accounts.apply(
+            AdminAction::CancelRoleTransfer { role },
+            ctx.bumps.timelock_operation,
+        )
+    }
+
+    // ========================================================================
+    // MULTISIG
+    // ========================================================================
+    /// Hand admin actions over to an M-of-N multisig
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.config.multisig.is_none(),
+            SSSOneError::MultisigAlreadyEnabled
+        );
+        MultisigConfig::validate(&signers, threshold)?;
+        
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.config = ctx.accounts.config.key();
+        multisig.signers = signers.clone();
+        multisig.threshold = threshold;
+        multisig.proposal_count = 0;
+        multisig.bump = ctx.bumps.multisig;
+        
+        ctx.accounts.config.multisig = Some(multisig.key());
+        
+        emit!(MultisigCreated {
+            multisig: multisig.key(),
+            signers,
+            threshold,
+        });
+        Ok(())
+    }
+
+    /// Propose an admin action; counts as the proposer's approval
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        action: AdminAction,
+    ) -> Result<()> {
+        if let AdminAction::UpdateMultisig { signers, threshold } = &action {
+            MultisigConfig::validate(signers, *threshold)?;
+        }
+        
+        let multisig = &mut ctx.accounts.multisig;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.multisig = multisig.key();
+        proposal.index = multisig.proposal_count;
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.action = action.clone();
+        proposal.approvals = vec![ctx.accounts.proposer.key()];
+        proposal.executed = false;
+        proposal.created_at = Clock::get()?.unix_timestamp;
+        proposal.bump = ctx.bumps.proposal;
+        
+        multisig.proposal_count = multisig.proposal_count.checked_add(1)
+            .ok_or(SSSOneError::Overflow)?;
+        
+        emit!(ProposalCreated {
+            proposal: proposal.key(),
+            index: proposal.index,
+            proposer: proposal.proposer,
+            action,
+        });
+        Ok(())
+    }
+
+    /// Approve a pending proposal
+    pub fn approve(ctx: Context<ApproveProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let approver = ctx.accounts.approver.key();
+        require!(!proposal.executed, SSSOneError::ProposalAlreadyExecuted);
+        require!(
+            !proposal.approvals.contains(&approver),
+            SSSOneError::AlreadyApproved
+        );
+        proposal.approvals.push(approver);
+        
+        emit!(ProposalApproved {
+            proposal: proposal.key(),
+            approver,
+            approvals: proposal.approvals.len() as u8,
+        });
+        Ok(())
+    }
+
+    /// Apply a proposal's action once it reaches the threshold
+    pub fn execute(ctx: Context<ExecuteProposal>) -> Result<()> {
+        let executor = ctx.accounts.executor.key();
+        let multisig = &mut ctx.accounts.multisig;
+        let proposal = &mut ctx.accounts.proposal;
+        admin::consume_proposal(multisig, proposal)?;
+        
+        match &proposal.action {
+            AdminAction::UpdateMultisig { signers, threshold } => {
+                MultisigConfig::validate(signers, *threshold)?;
+                multisig.signers = signers.clone();
+                multisig.threshold = *threshold;
+                emit!(MultisigUpdated {
+                    multisig: multisig.key(),
+                    signers: signers.clone(),
+                    threshold: *threshold,
+                });
+            }
+            action => admin::apply_or_queue(
+                &mut ctx.accounts.config,
+                action.clone(),
+                multisig.key(),
+                executor,
+                ctx.accounts.timelock_operation.as_mut(),
+                ctx.bumps.timelock_operation,
+            )?,
+        }
+        
+        emit!(ProposalExecuted {
+            proposal: proposal.key(),
+            executor,
+        });
+        Ok(())
+    }
+
+    // ========================================================================
+    // TIMELOCK
+    // ========================================================================
+    /// Enable, change or disable the timelock delay. Changes to an active
+    /// timelock are themselves queued.
+    pub fn set_timelock_delay(
         ctx: Context<AuthorityManagement>,
-        new_pauser: Pubkey,
+        delay: Option<i64>,
+    ) -> Result<()> {
+        ctx.accounts.apply(
+            AdminAction::SetTimelockDelay { delay },
+            ctx.bumps.timelock_operation,
+        )
+    }
+
+    /// Apply a queued operation once its ETA has passed. Permissionless.
+    pub fn execute_timelock_operation(ctx: Context<ExecuteTimelockOperation>) -> Result<()> {
+        let operation = &ctx.accounts.timelock_operation;
+        require!(
+            Clock::get()?.unix_timestamp >= operation.eta,
+            SSSOneError::TimelockNotReady
+        );
+        
+        operation.action.apply(&mut ctx.accounts.config, operation.proposer)?;
+        
+        emit!(OperationExecuted {
+            operation: operation.key(),
+            index: operation.index,
+        });
+        Ok(())
+    }
+
+    /// Cancel a queued operation before it executes
+    pub fn cancel_timelock_operation(ctx: Context<CancelTimelockOperation>) -> Result<()> {
+        let operation = &ctx.accounts.timelock_operation;
+        emit!(OperationCancelled {
+            operation: operation.key(),
+            index: operation.index,
+            guardian: ctx.accounts.guardian.key(),
+        });
+        msg!("Timelock operation {} cancelled", operation.index);
+        Ok(())
+    }
+
+    // ========================================================================
+    // BLACKLIST MANAGEMENT
+    // ========================================================================
+    /// Blacklist a wallet so the transfer hook rejects its transfers
+    pub fn add_to_blacklist(
+        ctx: Context<AddToBlacklist>,
+        reason_code: u8,
+        case_reference: [u8; 32],
     ) -> Result<()> {
-        ctx.accounts.config.pauser = new_pauser;
-        emit!(PauserUpdated { new_pauser });
+        let entry = &mut ctx.accounts.blacklist_entry;
+        entry.config = ctx.accounts.config.key();
+        entry.wallet = ctx.accounts.wallet.key();
+        entry.reason_code = reason_code;
+        entry.case_reference = case_reference;
+        entry.blacklisted_at = Clock::get()?.unix_timestamp;
+        entry.blacklister = ctx.accounts.blacklister.key();
+        entry.bump = ctx.bumps.blacklist_entry;
+        
+        emit!(Blacklisted {
+            wallet: entry.wallet,
+            reason_code,
+            case_reference,
+            blacklister: entry.blacklister,
+        });
+        
+        msg!("Wallet blacklisted: {}", entry.wallet);
+        Ok(())
+    }
+
+    /// Remove a wallet from the blacklist, closing its entry
+    pub fn remove_from_blacklist(
+        ctx: Context<RemoveFromBlacklist>,
+    ) -> Result<()> {
+        emit!(Unblacklisted {
+            wallet: ctx.accounts.wallet.key(),
+            blacklister: ctx.accounts.blacklister.key(),
+        });
+        
+        msg!("Wallet removed from blacklist: {}", ctx.accounts.wallet.key());
+        Ok(())
+    }
+
+    // ========================================================================
+    // KYC MANAGEMENT
+    // ========================================================================
+    /// Record or update a holder's KYC approval. On allowlist mints the
+    /// holder's token account, if given, is thawed.
+    pub fn approve_holder(
+        ctx: Context<ApproveHolder>,
+        tier: u8,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        let allowlist = extensions::is_default_frozen(&ctx.accounts.mint.to_account_info())?;
+        if let Some(token_account) = ctx.accounts.token_account.as_ref()
+            .filter(|token_account| allowlist && token_account.is_frozen())
+        {
+            ctx.accounts.config.require_not_paused(PauseFlags::THAW)?;
+            
+            let config_key = ctx.accounts.config.key();
+            let seeds: &[&[u8]] = &[
+                b"mint_authority",
+                config_key.as_ref(),
+                &[ctx.accounts.config.mint_authority_bump],
+            ];
+            let signer_seeds = &[seeds];
+            let cpi_accounts = token_interface::ThawAccount {
+                account: token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token_interface::thaw_account(cpi_ctx)?;
+            
+            emit!(AccountThawed {
+                account: token_account.key(),
+            });
+        }
+        
+        let record = &mut ctx.accounts.kyc_record;
+        record.config = ctx.accounts.config.key();
+        record.holder = ctx.accounts.holder.key();
+        record.tier = tier;
+        record.expires_at = expires_at;
+        record.approved_at = Clock::get()?.unix_timestamp;
+        record.kyc_officer = ctx.accounts.kyc_officer.key();
+        record.bump = ctx.bumps.kyc_record;
+        
+        emit!(HolderApproved {
+            holder: record.holder,
+            tier,
+            expires_at,
+            kyc_officer: record.kyc_officer,
+        });
+        
+        msg!("Holder approved: {}", record.holder);
+        Ok(())
+    }
+
+    /// Revoke a holder's KYC approval, closing its record. On allowlist
+    /// mints the holder's token account is frozen again.
+    pub fn revoke_holder(
+        ctx: Context<RevokeHolder>,
+    ) -> Result<()> {
+        let allowlist = extensions::is_default_frozen(&ctx.accounts.mint.to_account_info())?;
+        if allowlist {
+            require!(
+                ctx.accounts.token_account.is_some(),
+                SSSOneError::HolderTokenAccountRequired
+            );
+        }
+        if let Some(token_account) = ctx.accounts.token_account.as_ref()
+            .filter(|token_account| allowlist && !token_account.is_frozen())
+        {
+            let config_key = ctx.accounts.config.key();
+            let seeds: &[&[u8]] = &[
+                b"mint_authority",
+                config_key.as_ref(),
+                &[ctx.accounts.config.mint_authority_bump],
+            ];
+            let signer_seeds = &[seeds];
+            let cpi_accounts = token_interface::FreezeAccount {
+                account: token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token_interface::freeze_account(cpi_ctx)?;
+            
+            emit!(AccountFrozen {
+                account: token_account.key(),
+            });
+        }
+        
+        emit!(HolderRevoked {
+            holder: ctx.accounts.holder.key(),
+            kyc_officer: ctx.accounts.kyc_officer.key(),
+        });
+        
+        msg!("Holder revoked: {}", ctx.accounts.holder.key());
         Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,307 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::error::SSSOneError;
+use crate::state::{PauseFlags, StablecoinConfig};
+
+/// Size of a version 0 config
+pub const V0_SIZE: usize = 
+    4 + 32 +    // name
+    4 + 10 +    // symbol
+    4 + 200 +   // uri
+    1 +         // decimals
+    32 +        // mint
+    32 * 4 +    // authority, minter, burner, pauser
+    1 +         // is_paused
+    1 +         // initialized
+    8;          // total_supply
+
+/// Version 0 layout: the unversioned config of coins created as keypair
+/// accounts, before config and mint became PDAs
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct StablecoinConfigV0 {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub decimals: u8,
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub minter: Pubkey,
+    pub burner: Pubkey,
+    pub pauser: Pubkey,
+    pub is_paused: bool,
+    pub initialized: bool,
+    pub total_supply: u64,
+}
+
+impl StablecoinConfigV0 {
+    /// Read a version 0 config account. Versioned configs are larger, so
+    /// they are recognised by size and rejected.
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= 8 && data[..8] == StablecoinConfig::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        require!(data.len() == 8 + V0_SIZE, SSSOneError::ConfigAlreadyMigrated);
+        Ok(Self::deserialize(&mut &data[8..])?)
+    }
+}
+
+/// Upgrade a version 0 config to the current layout. The single minter
+/// becomes the master minter, and roles version 0 lacked go to the
+/// authority, as in `initialize`. Bumps are left for the caller.
+pub fn upgrade_config(legacy: StablecoinConfigV0) -> Result<StablecoinConfig> {
+    StablecoinConfig::validate_metadata(&legacy.name, &legacy.symbol, &legacy.uri)?;
+    
+    let authority = legacy.authority;
+    Ok(StablecoinConfig {
+        name: legacy.name,
+        symbol: legacy.symbol,
+        uri: legacy.uri,
+        decimals: legacy.decimals,
+        mint: legacy.mint,
+        bump: 0,
+        mint_bump: 0,
+        mint_authority_bump: 0,
+        permanent_delegate_bump: 0,
+        authority,
+        master_minter: legacy.minter,
+        burner: legacy.burner,
+        pauser: legacy.pauser,
+        blacklister: authority,
+        guardian: authority,
+        freezer: authority,
+        kyc_officer: authority,
+        attestor: authority,
+        metadata_authority: authority,
+        pending_authority: None,
+        pending_master_minter: None,
+        pending_burner: None,
+        pending_pauser: None,
+        pending_blacklister: None,
+        pending_guardian: None,
+        pending_freezer: None,
+        pending_kyc_officer: None,
+        pending_attestor: None,
+        pending_metadata_authority: None,
+        multisig: None,
+        timelock_delay: None,
+        timelock_count: 0,
+        redemption_count: 0,
+        paused_operations: if legacy.is_paused { PauseFlags::ALL } else { 0 },
+        initialized: legacy.initialized,
+        pause_on_supply_discrepancy: false,
+        redeem_requires_kyc: false,
+        total_supply: legacy.total_supply,
+        max_supply: None,
+        mint_rate_limit: None,
+        reserve_policy: None,
+        version: StablecoinConfig::CURRENT_VERSION,
+        fee_admin: authority,
+        pending_fee_admin: None,
+        rate_authority: authority,
+        pending_rate_authority: None,
+        auditor_elgamal_pubkey: None,
+        mint_seed: 0,
+        reserved: [0; 128],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{RateLimit, ReservePolicy};
+
+    fn key(seed: u8) -> Pubkey {
+        Pubkey::new_from_array([seed; 32])
+    }
+
+    /// Version 0 config with every string at its maximum length
+    fn full_v0_config() -> StablecoinConfigV0 {
+        StablecoinConfigV0 {
+            name: "n".repeat(StablecoinConfig::MAX_NAME_LEN),
+            symbol: "s".repeat(StablecoinConfig::MAX_SYMBOL_LEN),
+            uri: "u".repeat(StablecoinConfig::MAX_URI_LEN),
+            decimals: 6,
+            mint: key(1),
+            authority: key(2),
+            minter: key(3),
+            burner: key(4),
+            pauser: key(5),
+            is_paused: true,
+            initialized: true,
+            total_supply: 1_000_000,
+        }
+    }
+
+    /// Version 0 config as written by the original `initialize`
+    fn sparse_v0_config() -> StablecoinConfigV0 {
+        StablecoinConfigV0 {
+            name: "Stable".to_string(),
+            symbol: "STBL".to_string(),
+            uri: String::new(),
+            is_paused: false,
+            ..full_v0_config()
+        }
+    }
+
+    /// Current config expected from upgrading `legacy`
+    fn upgraded(legacy: &StablecoinConfigV0) -> StablecoinConfig {
+        StablecoinConfig {
+            name: legacy.name.clone(),
+            symbol: legacy.symbol.clone(),
+            uri: legacy.uri.clone(),
+            decimals: 6,
+            mint: key(1),
+            bump: 0,
+            mint_bump: 0,
+            mint_authority_bump: 0,
+            permanent_delegate_bump: 0,
+            authority: key(2),
+            master_minter: key(3),
+            burner: key(4),
+            pauser: key(5),
+            blacklister: key(2),
+            guardian: key(2),
+            freezer: key(2),
+            kyc_officer: key(2),
+            attestor: key(2),
+            metadata_authority: key(2),
+            pending_authority: None,
+            pending_master_minter: None,
+            pending_burner: None,
+            pending_pauser: None,
+            pending_blacklister: None,
+            pending_guardian: None,
+            pending_freezer: None,
+            pending_kyc_officer: None,
+            pending_attestor: None,
+            pending_metadata_authority: None,
+            multisig: None,
+            timelock_delay: None,
+            timelock_count: 0,
+            redemption_count: 0,
+            paused_operations: if legacy.is_paused { PauseFlags::ALL } else { 0 },
+            initialized: true,
+            pause_on_supply_discrepancy: false,
+            redeem_requires_kyc: false,
+            total_supply: 1_000_000,
+            max_supply: None,
+            mint_rate_limit: None,
+            reserve_policy: None,
+            version: StablecoinConfig::CURRENT_VERSION,
+            fee_admin: key(2),
+            pending_fee_admin: None,
+            rate_authority: key(2),
+            pending_rate_authority: None,
+            auditor_elgamal_pubkey: None,
+            mint_seed: 0,
+            reserved: [0; 128],
+        }
+    }
+
+    /// Version 0 account data, as allocated by the original `initialize`
+    fn v0_fixture(legacy: &StablecoinConfigV0) -> Vec<u8> {
+        let mut data = StablecoinConfig::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        assert!(data.len() <= 8 + V0_SIZE);
+        data.resize(8 + V0_SIZE, 0);
+        data
+    }
+
+    fn serialized(config: &StablecoinConfig) -> Vec<u8> {
+        let mut data = Vec::new();
+        config.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn v0_size_matches_full_config() {
+        let mut data = Vec::new();
+        full_v0_config().serialize(&mut data).unwrap();
+        assert_eq!(data.len(), V0_SIZE);
+    }
+
+    #[test]
+    fn size_matches_full_config() {
+        let config = StablecoinConfig {
+            pending_authority: Some(key(12)),
+            pending_master_minter: Some(key(13)),
+            pending_burner: Some(key(14)),
+            pending_pauser: Some(key(15)),
+            pending_blacklister: Some(key(16)),
+            pending_guardian: Some(key(17)),
+            pending_freezer: Some(key(18)),
+            pending_kyc_officer: Some(key(19)),
+            pending_attestor: Some(key(20)),
+            pending_metadata_authority: Some(key(21)),
+            multisig: Some(key(22)),
+            timelock_delay: Some(86_400),
+            max_supply: Some(5_000_000),
+            mint_rate_limit: Some(RateLimit {
+                max_amount: 10_000,
+                window_seconds: 3_600,
+                updated_at: 1_700_000_000,
+                used: 500,
+            }),
+            reserve_policy: Some(ReservePolicy {
+                ratio_bps: 10_000,
+                max_staleness: 86_400,
+            }),
+            pending_fee_admin: Some(key(23)),
+            pending_rate_authority: Some(key(24)),
+            auditor_elgamal_pubkey: Some([25; 32]),
+            ..upgraded(&full_v0_config())
+        };
+        
+        assert_eq!(serialized(&config).len(), 8 + StablecoinConfig::SIZE);
+    }
+
+    #[test]
+    fn upgrades_full_v0_config() {
+        let data = v0_fixture(&full_v0_config());
+        
+        let legacy = StablecoinConfigV0::try_from_account_data(&data).unwrap();
+        let config = upgrade_config(legacy).unwrap();
+        
+        assert_eq!(config.paused_operations, PauseFlags::ALL);
+        assert_eq!(serialized(&config), serialized(&upgraded(&full_v0_config())));
+    }
+
+    #[test]
+    fn upgrades_sparse_v0_config() {
+        let data = v0_fixture(&sparse_v0_config());
+        
+        let legacy = StablecoinConfigV0::try_from_account_data(&data).unwrap();
+        let config = upgrade_config(legacy).unwrap();
+        
+        assert_eq!(config.paused_operations, 0);
+        assert_eq!(serialized(&config), serialized(&upgraded(&sparse_v0_config())));
+    }
+
+    #[test]
+    fn upgrades_v0_config_with_stale_tail() {
+        // The name was shortened after creation, leaving the end of the
+        // longer encoding behind
+        let mut data = v0_fixture(&full_v0_config());
+        let shortened = sparse_v0_config();
+        let mut written = StablecoinConfig::DISCRIMINATOR.to_vec();
+        shortened.serialize(&mut written).unwrap();
+        data[..written.len()].copy_from_slice(&written);
+        assert!(data[written.len()..].iter().any(|byte| *byte != 0));
+        
+        let legacy = StablecoinConfigV0::try_from_account_data(&data).unwrap();
+        let config = upgrade_config(legacy).unwrap();
+        
+        assert_eq!(serialized(&config), serialized(&upgraded(&shortened)));
+    }
+
+    #[test]
+    fn rejects_current_version() {
+        let mut data = serialized(&upgraded(&full_v0_config()));
+        data.resize(8 + StablecoinConfig::SIZE, 0);
+        
+        let err = StablecoinConfigV0::try_from_account_data(&data).err().unwrap();
+        
+        assert_eq!(err, SSSOneError::ConfigAlreadyMigrated.into());
+    }
+}
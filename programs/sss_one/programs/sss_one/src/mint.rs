@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, TokenAccount};
+use crate::error::SSSOneError;
+use crate::events::*;
+use crate::instructions::{BatchMint, MintOperation};
+use crate::state::{MinterInfo, PauseFlags, ReserveAttestation, StablecoinConfig};
+
+/// Check pause, allowance, supply cap and reserve policy before minting
+/// `amount`
+fn check_mint(
+    config: &StablecoinConfig,
+    minter_info: &MinterInfo,
+    reserve_attestation: Option<&ReserveAttestation>,
+    amount: u64,
+) -> Result<()> {
+    config.require_not_paused(PauseFlags::MINT)?;
+    require!(config.initialized, SSSOneError::NotInitialized);
+    require!(
+        amount <= minter_info.allowance,
+        SSSOneError::MinterAllowanceExceeded
+    );
+    if let Some(max_supply) = config.max_supply {
+        let new_supply = config.total_supply.checked_add(amount)
+            .ok_or(SSSOneError::Overflow)?;
+        require!(new_supply <= max_supply, SSSOneError::SupplyCapExceeded);
+    }
+    if let Some(policy) = config.reserve_policy {
+        let attestation = reserve_attestation
+            .ok_or(SSSOneError::ReserveAttestationRequired)?;
+        let new_supply = config.total_supply.checked_add(amount)
+            .ok_or(SSSOneError::Overflow)?;
+        policy.check(attestation, new_supply, Clock::get()?.unix_timestamp)?;
+    }
+    Ok(())
+}
+
+/// Consume allowance and rate limits, then update total supply
+fn record_mint(
+    config: &mut StablecoinConfig,
+    minter_info: &mut MinterInfo,
+    amount: u64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    minter_info.allowance = minter_info.allowance.checked_sub(amount)
+        .ok_or(SSSOneError::Underflow)?;
+    if let Some(rate_limit) = minter_info.rate_limit.as_mut() {
+        rate_limit.consume(amount, now)?;
+    }
+    
+    if let Some(rate_limit) = config.mint_rate_limit.as_mut() {
+        rate_limit.consume(amount, now)?;
+    }
+    config.total_supply = config.total_supply.checked_add(amount)
+        .ok_or(SSSOneError::Overflow)?;
+    Ok(())
+}
+
+impl<'info> MintOperation<'info> {
+    /// Mint to the recipient after checking pause, allowance, supply cap
+    /// and rate limits. `reference` is the off-chain reference, if any.
+    pub fn mint_tokens(&mut self, amount: u64, reference: Option<[u8; 32]>) -> Result<()> {
+        let config = &self.config;
+        check_mint(config, &self.minter_info, self.reserve_attestation.as_deref(), amount)?;
+        
+        // Mint tokens, signing as the mint authority PDA
+        let config_key = config.key();
+        let seeds: &[&[u8]] = &[
+            b"mint_authority",
+            config_key.as_ref(),
+            &[config.mint_authority_bump],
+        ];
+        let signer_seeds = &[seeds];
+        let cpi_accounts = token_interface::MintTo {
+            mint: self.mint.to_account_info(),
+            to: self.recipient_token_account.to_account_info(),
+            authority: self.mint_authority.to_account_info(),
+        };
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token_interface::mint_to(cpi_ctx, amount)?;
+        
+        record_mint(&mut self.config, &mut self.minter_info, amount)?;
+        
+        emit!(TokensMinted {
+            recipient: self.recipient.key(),
+            amount,
+            new_supply: self.config.total_supply,
+            reference,
+        });
+        
+        msg!("Minted {} tokens to {}", amount, self.recipient.key());
+        Ok(())
+    }
+}
+
+impl<'info> BatchMint<'info> {
+    /// Mint `amounts[i]` to the i-th recipient token account. Limits are
+    /// checked and consumed once for the batch total.
+    pub fn batch_mint_tokens(
+        &mut self,
+        recipients: &'info [AccountInfo<'info>],
+        amounts: &[u64],
+    ) -> Result<()> {
+        require!(!amounts.is_empty(), SSSOneError::InvalidAmount);
+        require!(
+            amounts.len() == recipients.len(),
+            SSSOneError::BatchLengthMismatch
+        );
+        let total = amounts.iter()
+            .try_fold(0u64, |total, amount| total.checked_add(*amount))
+            .ok_or(SSSOneError::Overflow)?;
+        
+        let config = &self.config;
+        check_mint(config, &self.minter_info, self.reserve_attestation.as_deref(), total)?;
+        
+        // Mint to each recipient, signing as the mint authority PDA
+        let config_key = config.key();
+        let seeds: &[&[u8]] = &[
+            b"mint_authority",
+            config_key.as_ref(),
+            &[config.mint_authority_bump],
+        ];
+        let signer_seeds = &[seeds];
+        let mut new_supply = config.total_supply;
+        for (account_info, amount) in recipients.iter().zip(amounts) {
+            let recipient_token_account = InterfaceAccount::<TokenAccount>::try_from(account_info)?;
+            require!(
+                recipient_token_account.mint == config.mint,
+                SSSOneError::InvalidRecipientMint
+            );
+            
+            let cpi_accounts = token_interface::MintTo {
+                mint: self.mint.to_account_info(),
+                to: account_info.clone(),
+                authority: self.mint_authority.to_account_info(),
+            };
+            let cpi_program = self.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token_interface::mint_to(cpi_ctx, *amount)?;
+            
+            new_supply = new_supply.checked_add(*amount)
+                .ok_or(SSSOneError::Overflow)?;
+            emit!(TokensMinted {
+                recipient: recipient_token_account.owner,
+                amount: *amount,
+                new_supply,
+                reference: None,
+            });
+        }
+        
+        record_mint(&mut self.config, &mut self.minter_info, total)?;
+        
+        emit!(BatchMinted {
+            recipients: recipients.len() as u32,
+            total_amount: total,
+            new_supply: self.config.total_supply,
+            minter: self.minter.key(),
+        });
+        
+        msg!("Batch minted {} tokens to {} recipients", total, recipients.len());
+        Ok(())
+    }
+}
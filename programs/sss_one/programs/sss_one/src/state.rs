@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::error::SSSOneError;
 
 /// Configuration account for the SSS-1 stablecoin
 #[account]
@@ -12,29 +13,752 @@ pub struct StablecoinConfig {
     /// Token mint address
     pub mint: Pubkey,
     
+    /// PDA bumps for the config, mint, mint authority and permanent delegate
+    pub bump: u8,
+    pub mint_bump: u8,
+    pub mint_authority_bump: u8,
+    pub permanent_delegate_bump: u8,
+    
     /// Authority roles
     pub authority: Pubkey,
-    pub minter: Pubkey,
+    pub master_minter: Pubkey,
     pub burner: Pubkey,
     pub pauser: Pubkey,
+    pub blacklister: Pubkey,
+    pub guardian: Pubkey,
+    pub freezer: Pubkey,
+    pub kyc_officer: Pubkey,
+    pub attestor: Pubkey,
+    pub metadata_authority: Pubkey,
+    
+    /// Keys proposed for a role, pending acceptance
+    pub pending_authority: Option<Pubkey>,
+    pub pending_master_minter: Option<Pubkey>,
+    pub pending_burner: Option<Pubkey>,
+    pub pending_pauser: Option<Pubkey>,
+    pub pending_blacklister: Option<Pubkey>,
+    pub pending_guardian: Option<Pubkey>,
+    pub pending_freezer: Option<Pubkey>,
+    pub pending_kyc_officer: Option<Pubkey>,
+    pub pending_attestor: Option<Pubkey>,
+    pub pending_metadata_authority: Option<Pubkey>,
+    
+    /// Multisig that must approve admin actions, if enabled
+    pub multisig: Option<Pubkey>,
+    
+    /// Delay applied to sensitive admin actions, if the timelock is enabled
+    pub timelock_delay: Option<i64>,
     
-    /// State
-    pub is_paused: bool,
+    /// Index of the next queued timelock operation
+    pub timelock_count: u64,
+    
+    /// Index of the next redemption ticket
+    pub redemption_count: u64,
+    
+    /// State. `paused_operations` is a set of `PauseFlags`.
+    pub paused_operations: u8,
     pub initialized: bool,
     
+    /// Pause when `reconcile_supply` finds more tokens on the mint than tracked
+    pub pause_on_supply_discrepancy: bool,
+    
+    /// Limit `redeem` and `request_redemption` to holders with a valid KYC record
+    pub redeem_requires_kyc: bool,
+    
     /// Total supply tracking
     pub total_supply: u64,
+    
+    /// Maximum total supply `mint` may reach, if capped
+    pub max_supply: Option<u64>,
+    
+    /// Config-wide mint rate limit
+    pub mint_rate_limit: Option<RateLimit>,
+    
+    /// Backing required by `mint`, if enforced
+    pub reserve_policy: Option<ReservePolicy>,
+    
+    /// Layout version. Fields added later go after it, carved out of
+    /// `reserved`, so that older accounts read them as zero.
+    pub version: u8,
+    
+    /// Transfer fee admin
+    pub fee_admin: Pubkey,
+    pub pending_fee_admin: Option<Pubkey>,
+    
+    /// Interest rate authority
+    pub rate_authority: Pubkey,
+    pub pending_rate_authority: Option<Pubkey>,
+    
+    /// ElGamal public key of the confidential transfer auditor
+    pub auditor_elgamal_pubkey: Option<[u8; 32]>,
+    
+    /// Seed of the mint PDA, next to the initial authority
+    pub mint_seed: u64,
+    
+    /// Reserved for future fields
+    pub reserved: [u8; 128],
 }
 
 impl StablecoinConfig {
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const MAX_SYMBOL_LEN: usize = 10;
+    pub const MAX_URI_LEN: usize = 200;
+    
+    /// Layout version written by `initialize` and `migrate_config`.
+    /// Version 0 is the unversioned layout of configs created as keypair
+    /// accounts, which `migrate_config` moves to the config PDA.
+    pub const CURRENT_VERSION: u8 = 1;
+    
     pub const SIZE: usize = 
-        4 + 32 +    // name (String with max 32 chars)
-        4 + 10 +    // symbol (String with max 10 chars)
-        4 + 200 +   // uri (String with max 200 chars)
+        4 + Self::MAX_NAME_LEN +    // name
+        4 + Self::MAX_SYMBOL_LEN +  // symbol
+        4 + Self::MAX_URI_LEN +     // uri
         1 +         // decimals
         32 +        // mint
-        32 * 4 +    // authority, minter, burner, pauser
-        1 +         // is_paused
+        4 +         // bump, mint_bump, mint_authority_bump, permanent_delegate_bump
+        32 * 10 +   // authority, master_minter, burner, pauser, blacklister, guardian, freezer, kyc_officer, attestor, metadata_authority
+        33 * 10 +   // pending authority and role holders
+        33 +        // multisig
+        1 + 8 +     // timelock_delay
+        8 +         // timelock_count
+        8 +         // redemption_count
+        1 +         // paused_operations
         1 +         // initialized
-        8;          // total_supply
+        1 +         // pause_on_supply_discrepancy
+        1 +         // redeem_requires_kyc
+        8 +         // total_supply
+        1 + 8 +     // max_supply
+        1 + RateLimit::SIZE + // mint_rate_limit
+        1 + ReservePolicy::SIZE + // reserve_policy
+        1 +         // version
+        32 +        // fee_admin
+        33 +        // pending_fee_admin
+        32 +        // rate_authority
+        33 +        // pending_rate_authority
+        1 + 32 +    // auditor_elgamal_pubkey
+        8 +         // mint_seed
+        128;        // reserved
+    
+    pub fn validate_metadata(name: &str, symbol: &str, uri: &str) -> Result<()> {
+        require!(name.len() <= Self::MAX_NAME_LEN, SSSOneError::NameTooLong);
+        require!(symbol.len() <= Self::MAX_SYMBOL_LEN, SSSOneError::SymbolTooLong);
+        require!(uri.len() <= Self::MAX_URI_LEN, SSSOneError::URITooLong);
+        Ok(())
+    }
+    
+    /// Fail if any of the operations in `flags` is paused
+    pub fn require_not_paused(&self, flags: u8) -> Result<()> {
+        require!(self.paused_operations & flags == 0, SSSOneError::ProgramPaused);
+        Ok(())
+    }
+    
+    /// Pause the operations in `mask`, returning the flags that changed
+    pub fn pause_operations(&mut self, mask: u8) -> Result<u8> {
+        PauseFlags::validate(mask)?;
+        let changed = mask & !self.paused_operations;
+        require!(changed != 0, SSSOneError::AlreadyPaused);
+        self.paused_operations |= mask;
+        Ok(changed)
+    }
+    
+    /// Unpause the operations in `mask`, returning the flags that changed
+    pub fn unpause_operations(&mut self, mask: u8) -> Result<u8> {
+        PauseFlags::validate(mask)?;
+        let changed = mask & self.paused_operations;
+        require!(changed != 0, SSSOneError::NotPaused);
+        self.paused_operations &= !mask;
+        Ok(changed)
+    }
+    
+    /// Enforce the KYC requirement for holder redemptions, if enabled
+    pub fn check_redeem_kyc(&self, kyc_record: Option<&KycRecord>, now: i64) -> Result<()> {
+        if self.redeem_requires_kyc {
+            let kyc_record = kyc_record.ok_or(SSSOneError::KycRequired)?;
+            require!(kyc_record.is_valid(now), SSSOneError::KycExpired);
+        }
+        Ok(())
+    }
+    
+    pub fn role_mut(&mut self, role: Role) -> &mut Pubkey {
+        match role {
+            Role::MasterMinter => &mut self.master_minter,
+            Role::Burner => &mut self.burner,
+            Role::Pauser => &mut self.pauser,
+            Role::Blacklister => &mut self.blacklister,
+            Role::Guardian => &mut self.guardian,
+            Role::Freezer => &mut self.freezer,
+            Role::KycOfficer => &mut self.kyc_officer,
+            Role::Attestor => &mut self.attestor,
+            Role::MetadataAuthority => &mut self.metadata_authority,
+            Role::FeeAdmin => &mut self.fee_admin,
+            Role::RateAuthority => &mut self.rate_authority,
+        }
+    }
+    
+    pub fn pending_role(&self, role: Role) -> Option<Pubkey> {
+        match role {
+            Role::MasterMinter => self.pending_master_minter,
+            Role::Burner => self.pending_burner,
+            Role::Pauser => self.pending_pauser,
+            Role::Blacklister => self.pending_blacklister,
+            Role::Guardian => self.pending_guardian,
+            Role::Freezer => self.pending_freezer,
+            Role::KycOfficer => self.pending_kyc_officer,
+            Role::Attestor => self.pending_attestor,
+            Role::MetadataAuthority => self.pending_metadata_authority,
+            Role::FeeAdmin => self.pending_fee_admin,
+            Role::RateAuthority => self.pending_rate_authority,
+        }
+    }
+    
+    pub fn pending_role_mut(&mut self, role: Role) -> &mut Option<Pubkey> {
+        match role {
+            Role::MasterMinter => &mut self.pending_master_minter,
+            Role::Burner => &mut self.pending_burner,
+            Role::Pauser => &mut self.pending_pauser,
+            Role::Blacklister => &mut self.pending_blacklister,
+            Role::Guardian => &mut self.pending_guardian,
+            Role::Freezer => &mut self.pending_freezer,
+            Role::KycOfficer => &mut self.pending_kyc_officer,
+            Role::Attestor => &mut self.pending_attestor,
+            Role::MetadataAuthority => &mut self.pending_metadata_authority,
+            Role::FeeAdmin => &mut self.pending_fee_admin,
+            Role::RateAuthority => &mut self.pending_rate_authority,
+        }
+    }
+}
+
+/// M-of-N multisig controlling a config's admin actions, seeded by config
+#[account]
+pub struct MultisigConfig {
+    pub config: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    
+    /// Index of the next proposal
+    pub proposal_count: u64,
+    
+    pub bump: u8,
+}
+
+impl MultisigConfig {
+    pub const MAX_SIGNERS: usize = 10;
+    
+    pub const SIZE: usize =
+        32 +        // config
+        4 + 32 * Self::MAX_SIGNERS + // signers
+        1 +         // threshold
+        8 +         // proposal_count
+        1;          // bump
+    
+    pub fn validate(signers: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            signers.len() <= Self::MAX_SIGNERS,
+            SSSOneError::TooManySigners
+        );
+        require!(
+            threshold > 0 && threshold as usize <= signers.len(),
+            SSSOneError::InvalidThreshold
+        );
+        for (i, signer) in signers.iter().enumerate() {
+            require!(
+                !signers[..i].contains(signer),
+                SSSOneError::DuplicateSigner
+            );
+        }
+        Ok(())
+    }
+    
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+}
+
+/// Admin action awaiting multisig approval, seeded by multisig and index
+#[account]
+pub struct Proposal {
+    pub multisig: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+    
+    /// Signers that approved this proposal
+    pub approvals: Vec<Pubkey>,
+    
+    pub executed: bool,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const SIZE: usize =
+        32 +        // multisig
+        8 +         // index
+        32 +        // proposer
+        AdminAction::SIZE + // action
+        4 + 32 * MultisigConfig::MAX_SIGNERS + // approvals
+        1 +         // executed
+        8 +         // created_at
+        1;          // bump
+}
+
+/// Admin actions that the authority key applies directly, or that a
+/// multisig applies by executing a proposal. Actions needing accounts beyond
+/// the config run through their own instruction, which consumes the proposal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum AdminAction {
+    ProposeAuthority { new_authority: Pubkey },
+    CancelAuthorityTransfer,
+    ProposeRole { role: Role, new_holder: Pubkey },
+    CancelRoleTransfer { role: Role },
+    SetMintRateLimit { max_amount: Option<u64>, window_seconds: i64 },
+    UnpauseOperations { mask: u8 },
+    UpdateMultisig { signers: Vec<Pubkey>, threshold: u8 },
+    SetTimelockDelay { delay: Option<i64> },
+    SetSupplyCap { max_supply: Option<u64> },
+    SetPauseOnSupplyDiscrepancy { enabled: bool },
+    SetRedeemRequiresKyc { enabled: bool },
+    SetReservePolicy { policy: Option<ReservePolicy> },
+    SetMinterRateLimit { minter: Pubkey, max_amount: Option<u64>, window_seconds: i64 },
+    ApproveConfidentialAccount { token_account: Pubkey },
+    RotateAuditorKey { auditor_elgamal_pubkey: [u8; 32] },
+    Seize { source_token_account: Pubkey, treasury_token_account: Pubkey, amount: Option<u64> },
+    SyncSupply { mint_supply: u64 },
+}
+
+impl AdminAction {
+    /// Largest variant: `UpdateMultisig`
+    pub const SIZE: usize =
+        1 +         // variant
+        4 + 32 * MultisigConfig::MAX_SIGNERS + // signers
+        1;          // threshold
+}
+
+/// Admin action queued behind the timelock, seeded by config and index
+#[account]
+pub struct TimelockOperation {
+    pub config: Pubkey,
+    pub index: u64,
+    pub action: AdminAction,
+    
+    /// Key that queued the action and the account that paid its rent
+    pub proposer: Pubkey,
+    pub payer: Pubkey,
+    
+    pub queued_at: i64,
+    /// Earliest time the action can be executed
+    pub eta: i64,
+    pub bump: u8,
+}
+
+impl TimelockOperation {
+    pub const SIZE: usize =
+        32 +        // config
+        8 +         // index
+        AdminAction::SIZE + // action
+        32 +        // proposer
+        32 +        // payer
+        8 +         // queued_at
+        8 +         // eta
+        1;          // bump
+}
+
+/// Roles assigned by the authority through the two-step handover
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    MasterMinter,
+    Burner,
+    Pauser,
+    Blacklister,
+    Guardian,
+    Freezer,
+    KycOfficer,
+    Attestor,
+    MetadataAuthority,
+    FeeAdmin,
+    RateAuthority,
+}
+
+/// Operations that can be paused independently, as bits of
+/// `StablecoinConfig::paused_operations`
+pub struct PauseFlags;
+
+impl PauseFlags {
+    pub const MINT: u8 = 1 << 0;
+    pub const BURN: u8 = 1 << 1;
+    /// Holder redemptions and redemption ticket settlement
+    pub const REDEEM: u8 = 1 << 2;
+    /// Transfers, enforced by the transfer hook
+    pub const TRANSFER: u8 = 1 << 3;
+    /// Thawing token accounts. Freezing is never paused, so compliance can
+    /// act while the config is paused.
+    pub const THAW: u8 = 1 << 4;
+    pub const SEIZE: u8 = 1 << 5;
+    pub const ALL: u8 =
+        Self::MINT | Self::BURN | Self::REDEEM | Self::TRANSFER | Self::THAW | Self::SEIZE;
+    
+    pub fn validate(mask: u8) -> Result<()> {
+        require!(mask != 0 && mask & !Self::ALL == 0, SSSOneError::InvalidPauseMask);
+        Ok(())
+    }
+}
+
+/// Per-minter allowance, seeded by config and minter.
+/// Managed by the master minter and consumed by `mint`.
+#[account]
+pub struct MinterInfo {
+    pub config: Pubkey,
+    pub minter: Pubkey,
+    
+    /// Remaining amount this minter may issue
+    pub allowance: u64,
+    
+    /// Per-minter mint rate limit
+    pub rate_limit: Option<RateLimit>,
+    
+    pub bump: u8,
+}
+
+impl MinterInfo {
+    pub const SIZE: usize =
+        32 +        // config
+        32 +        // minter
+        8 +         // allowance
+        1 + RateLimit::SIZE + // rate_limit
+        1;          // bump
+}
+
+/// Caps how much can be minted within any `window_seconds` span. Usage
+/// recovers linearly over the window, so there is no boundary at which the
+/// full allowance becomes available twice.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    pub max_amount: u64,
+    pub window_seconds: i64,
+    
+    /// Usage as of the last mint, decaying from `updated_at`
+    pub updated_at: i64,
+    pub used: u64,
+}
+
+impl RateLimit {
+    pub const SIZE: usize =
+        8 +         // max_amount
+        8 +         // window_seconds
+        8 +         // updated_at
+        8;          // used
+    
+    pub fn new(max_amount: u64, window_seconds: i64) -> Result<Self> {
+        require!(window_seconds > 0, SSSOneError::InvalidRateLimit);
+        Ok(Self {
+            max_amount,
+            window_seconds,
+            updated_at: 0,
+            used: 0,
+        })
+    }
+    
+    /// Usage remaining at `now`. Recovery rounds down, so the limit never
+    /// allows more than `max_amount` across a window.
+    pub fn used_at(&self, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.updated_at).max(0);
+        if elapsed >= self.window_seconds {
+            return 0;
+        }
+        let recovered = self.used as u128 * elapsed as u128 / self.window_seconds as u128;
+        self.used - recovered as u64
+    }
+    
+    /// Record `amount` against the decayed usage, failing if it would
+    /// exceed `max_amount`
+    pub fn consume(&mut self, amount: u64, now: i64) -> Result<()> {
+        let used = self.used_at(now).checked_add(amount)
+            .ok_or(SSSOneError::Overflow)?;
+        require!(used <= self.max_amount, SSSOneError::RateLimitExceeded);
+        self.used = used;
+        self.updated_at = now.max(self.updated_at);
+        Ok(())
+    }
+}
+
+/// Blacklist entry for a wallet, seeded by config and wallet.
+/// Its existence is what the transfer hook checks.
+#[account]
+pub struct BlacklistEntry {
+    pub config: Pubkey,
+    pub wallet: Pubkey,
+    
+    /// Issuer-defined reason code
+    pub reason_code: u8,
+    
+    /// Off-chain case reference (e.g. court order or ticket ID)
+    pub case_reference: [u8; 32],
+    
+    pub blacklisted_at: i64,
+    pub blacklister: Pubkey,
+    pub bump: u8,
+}
+
+impl BlacklistEntry {
+    pub const SIZE: usize =
+        32 +        // config
+        32 +        // wallet
+        1 +         // reason_code
+        32 +        // case_reference
+        8 +         // blacklisted_at
+        32 +        // blacklister
+        1;          // bump
+}
+
+/// KYC approval for a holder, seeded by config and holder
+#[account]
+pub struct KycRecord {
+    pub config: Pubkey,
+    pub holder: Pubkey,
+    
+    /// Issuer-defined KYC tier
+    pub tier: u8,
+    
+    /// Expiry timestamp, if the approval expires
+    pub expires_at: Option<i64>,
+    
+    pub approved_at: i64,
+    pub kyc_officer: Pubkey,
+    pub bump: u8,
+}
+
+impl KycRecord {
+    pub const SIZE: usize =
+        32 +        // config
+        32 +        // holder
+        1 +         // tier
+        1 + 8 +     // expires_at
+        8 +         // approved_at
+        32 +        // kyc_officer
+        1;          // bump
+    
+    pub fn is_valid(&self, now: i64) -> bool {
+        self.expires_at.map_or(true, |expires_at| now < expires_at)
+    }
+}
+
+/// Minting limit derived from the latest reserve attestation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReservePolicy {
+    /// Supply allowed per unit of reserves, in basis points
+    pub ratio_bps: u16,
+    
+    /// Maximum age of the attestation, in seconds
+    pub max_staleness: i64,
+}
+
+impl ReservePolicy {
+    pub const SIZE: usize =
+        2 +         // ratio_bps
+        8;          // max_staleness
+    
+    pub fn validate(&self) -> Result<()> {
+        require!(self.ratio_bps > 0, SSSOneError::InvalidReservePolicy);
+        require!(self.max_staleness > 0, SSSOneError::InvalidReservePolicy);
+        Ok(())
+    }
+    
+    /// Check that `new_supply` is backed by a fresh enough attestation
+    pub fn check(&self, attestation: &ReserveAttestation, new_supply: u64, now: i64) -> Result<()> {
+        let age = now.saturating_sub(attestation.timestamp);
+        require!(age <= self.max_staleness, SSSOneError::ReserveAttestationStale);
+        
+        let max_supply = (attestation.total_reserves as u128)
+            .checked_mul(self.ratio_bps as u128)
+            .ok_or(SSSOneError::Overflow)?
+            / 10_000;
+        require!(new_supply as u128 <= max_supply, SSSOneError::InsufficientReserves);
+        Ok(())
+    }
+}
+
+/// Latest reserve report published by the attestor, seeded by config
+#[account]
+pub struct ReserveAttestation {
+    pub config: Pubkey,
+    pub total_reserves: u64,
+    
+    /// Time the reserves were measured
+    pub timestamp: i64,
+    
+    /// Hash of the off-chain attestation report
+    pub report_hash: [u8; 32],
+    
+    pub attestor: Pubkey,
+    pub bump: u8,
+}
+
+impl ReserveAttestation {
+    pub const SIZE: usize =
+        32 +        // config
+        8 +         // total_reserves
+        8 +         // timestamp
+        32 +        // report_hash
+        32 +        // attestor
+        1;          // bump
+}
+
+/// Record of a mint made with an off-chain reference, seeded by config and
+/// reference so each reference mints at most once
+#[account]
+pub struct MintReceipt {
+    pub config: Pubkey,
+    pub reference: [u8; 32],
+    pub minter: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+    pub bump: u8,
+}
+
+impl MintReceipt {
+    pub const SIZE: usize =
+        32 +        // config
+        32 +        // reference
+        32 +        // minter
+        32 +        // recipient
+        8 +         // amount
+        8 +         // slot
+        1;          // bump
+}
+
+/// Escrowed redemption awaiting settlement by the burner
+#[account]
+pub struct RedemptionTicket {
+    pub config: Pubkey,
+    pub index: u64,
+    pub holder: Pubkey,
+    
+    /// Holder token account refunded on rejection
+    pub token_account: Pubkey,
+    
+    pub amount: u64,
+    pub payout_reference: [u8; 32],
+    pub status: RedemptionStatus,
+    pub created_at: i64,
+    
+    /// Set when the ticket is settled or rejected
+    pub settled_at: Option<i64>,
+    
+    pub bump: u8,
+}
+
+impl RedemptionTicket {
+    pub const SIZE: usize =
+        32 +        // config
+        8 +         // index
+        32 +        // holder
+        32 +        // token_account
+        8 +         // amount
+        32 +        // payout_reference
+        1 +         // status
+        8 +         // created_at
+        1 + 8 +     // settled_at
+        1;          // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RedemptionStatus {
+    Pending,
+    Settled,
+    Rejected,
+}
+
+/// Token-2022 extensions that can be enabled on the mint at initialization
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum MintExtension {
+    /// Lets the mint authority PDA close the mint once supply is zero
+    MintCloseAuthority,
+    /// Routes every transfer through the given transfer hook program
+    TransferHook { program_id: Pubkey },
+    /// Makes the permanent delegate PDA a delegate of every token account,
+    /// required for `seize`
+    PermanentDelegate,
+    /// Withholds a fee on every transfer, managed by the fee admin
+    TransferFee { basis_points: u16, max_fee: u64 },
+    /// Accrues interest in UI balances at a rate in basis points, managed
+    /// by the rate authority
+    InterestBearing { rate: i16 },
+    /// Confidential balances and transfers (SSS-3). Accounts must be
+    /// approved by the authority, and the auditor can decrypt every amount.
+    ConfidentialTransfer { auditor_elgamal_pubkey: [u8; 32] },
+    /// New token accounts start frozen until a KYC officer approves the
+    /// holder (allowlist preset)
+    DefaultAccountStateFrozen,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const START: i64 = 1_700_000_000;
+    
+    fn limit() -> RateLimit {
+        RateLimit::new(1_000, 100).unwrap()
+    }
+    
+    #[test]
+    fn rejects_empty_window() {
+        assert!(RateLimit::new(1_000, 0).is_err());
+    }
+    
+    #[test]
+    fn allows_full_amount_once() {
+        let mut limit = limit();
+        limit.consume(1_000, START).unwrap();
+        assert!(limit.consume(1, START).is_err());
+    }
+    
+    #[test]
+    fn no_burst_across_window_boundary() {
+        let mut limit = limit();
+        limit.consume(1, START).unwrap();
+        limit.consume(999, START + 99).unwrap();
+        
+        // A tumbling window starting at START would reset here
+        assert!(limit.consume(11, START + 100).is_err());
+        limit.consume(10, START + 100).unwrap();
+    }
+    
+    #[test]
+    fn recovers_linearly() {
+        let mut limit = limit();
+        limit.consume(1_000, START).unwrap();
+        assert_eq!(limit.used_at(START + 25), 750);
+        assert_eq!(limit.used_at(START + 50), 500);
+        
+        assert!(limit.consume(501, START + 50).is_err());
+        limit.consume(500, START + 50).unwrap();
+        assert_eq!(limit.used_at(START + 50), 1_000);
+    }
+    
+    #[test]
+    fn rounds_recovery_down() {
+        let mut limit = RateLimit::new(10, 3).unwrap();
+        limit.consume(10, START).unwrap();
+        assert_eq!(limit.used_at(START + 1), 7);
+        assert_eq!(limit.used_at(START + 2), 4);
+    }
+    
+    #[test]
+    fn fully_recovers_after_window() {
+        let mut limit = limit();
+        limit.consume(1_000, START).unwrap();
+        assert_eq!(limit.used_at(START + 99), 10);
+        assert_eq!(limit.used_at(START + 100), 0);
+        limit.consume(1_000, START + 100).unwrap();
+    }
+    
+    #[test]
+    fn clock_going_backwards_does_not_recover() {
+        let mut limit = limit();
+        limit.consume(1_000, START).unwrap();
+        assert_eq!(limit.used_at(START - 10), 1_000);
+        assert!(limit.consume(1, START - 10).is_err());
+    }
 }
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum TransferHookError {
+    #[msg("Hook was not invoked by a token transfer")]
+    NotTransferring,
+    
+    #[msg("Source owner is blacklisted")]
+    SourceBlacklisted,
+    
+    #[msg("Destination owner is blacklisted")]
+    DestinationBlacklisted,
+    
+    #[msg("Transfers are paused")]
+    TransfersPaused,
+}
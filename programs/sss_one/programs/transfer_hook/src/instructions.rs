@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use sss_one::state::StablecoinConfig;
+
+// ========================================================================
+// EXTRA ACCOUNT META LIST
+// ========================================================================
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    /// CHECK: Validation account read by Token-2022 to resolve extra accounts
+    #[account(
+        init,
+        payer = authority,
+        space = crate::extra_account_metas_size()?,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        seeds::program = sss_one::ID,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority,
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+// ========================================================================
+// EXECUTE
+// ========================================================================
+/// Account order follows the transfer hook interface: the first five
+/// accounts are fixed, the rest are resolved from the validation account
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(
+        token::mint = mint,
+    )]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        token::mint = mint,
+    )]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Source owner or delegate, already verified by the token program
+    pub owner: UncheckedAccount<'info>,
+    
+    /// CHECK: Validation account
+    #[account(
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    
+    /// CHECK: SSS program that owns the config and blacklist entries
+    #[account(
+        address = sss_one::ID,
+    )]
+    pub sss_program: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump = config.bump,
+        seeds::program = sss_program.key(),
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+    
+    /// CHECK: Blacklist entry PDA for the source owner, may not exist
+    #[account(
+        seeds = [b"blacklist", config.key().as_ref(), source_token.owner.as_ref()],
+        bump,
+        seeds::program = sss_program.key(),
+    )]
+    pub source_blacklist: UncheckedAccount<'info>,
+    
+    /// CHECK: Blacklist entry PDA for the destination owner, may not exist
+    #[account(
+        seeds = [b"blacklist", config.key().as_ref(), destination_token.owner.as_ref()],
+        bump,
+        seeds::program = sss_program.key(),
+    )]
+    pub destination_blacklist: UncheckedAccount<'info>,
+}
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions},
+    state::Account as TokenAccountState,
+};
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList};
+use spl_transfer_hook_interface::instruction::{ExecuteInstruction, TransferHookInstruction};
+use sss_one::state::PauseFlags;
+
+pub mod instructions;
+pub mod error;
+
+use instructions::*;
+use error::*;
+
+declare_id!("587YYVbFhVNSXRs7b26wtTX8PmCyXpRGJ6M5sSnhKWE9");
+
+/// Account indexes in the `Execute` instruction
+const SOURCE_TOKEN_INDEX: u8 = 0;
+const MINT_INDEX: u8 = 1;
+const DESTINATION_TOKEN_INDEX: u8 = 2;
+const SSS_PROGRAM_INDEX: u8 = 5;
+const CONFIG_INDEX: u8 = 6;
+
+/// Offset and length of the owner field in a token account
+const TOKEN_OWNER_OFFSET: u8 = 32;
+const TOKEN_OWNER_LEN: u8 = 32;
+
+/// Extra accounts appended to every `Execute`: the SSS program, the config
+/// PDA of the mint and the blacklist PDAs of the source and destination owners
+pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    let blacklist_seeds = |token_index: u8| {
+        vec![
+            Seed::Literal { bytes: b"blacklist".to_vec() },
+            Seed::AccountKey { index: CONFIG_INDEX },
+            Seed::AccountData {
+                account_index: token_index,
+                data_index: TOKEN_OWNER_OFFSET,
+                length: TOKEN_OWNER_LEN,
+            },
+        ]
+    };
+    Ok(vec![
+        ExtraAccountMeta::new_with_pubkey(&sss_one::ID, false, false)?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            SSS_PROGRAM_INDEX,
+            &[
+                Seed::Literal { bytes: b"config".to_vec() },
+                Seed::AccountKey { index: MINT_INDEX },
+            ],
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            SSS_PROGRAM_INDEX,
+            &blacklist_seeds(SOURCE_TOKEN_INDEX),
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            SSS_PROGRAM_INDEX,
+            &blacklist_seeds(DESTINATION_TOKEN_INDEX),
+            false,
+            false,
+        )?,
+    ])
+}
+
+/// Space needed for the validation account
+pub fn extra_account_metas_size() -> Result<usize> {
+    Ok(ExtraAccountMetaList::size_of(extra_account_metas()?.len())?)
+}
+
+/// A wallet is blacklisted when its entry PDA exists under the SSS program
+fn is_blacklisted(entry: &AccountInfo) -> bool {
+    entry.owner == &sss_one::ID && !entry.data_is_empty()
+}
+
+#[program]
+pub mod transfer_hook {
+    use super::*;
+
+    /// Write the extra accounts needed by `Execute` for this mint
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        let account_metas = extra_account_metas()?;
+        ExtraAccountMetaList::init::<ExecuteInstruction>(
+            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+            &account_metas,
+        )?;
+        
+        msg!("Extra account metas initialized for {}", ctx.accounts.mint.key());
+        Ok(())
+    }
+
+    /// Reject transfers while paused or from or to a blacklisted owner,
+    /// except seizures
+    pub fn transfer_hook(ctx: Context<TransferHook>, _amount: u64) -> Result<()> {
+        // Only run as part of a Token-2022 transfer
+        let source_info = ctx.accounts.source_token.to_account_info();
+        let source_data = source_info.try_borrow_data()?;
+        let source = StateWithExtensions::<TokenAccountState>::unpack(&source_data)?;
+        let hook_account = source.get_extension::<TransferHookAccount>()?;
+        require!(
+            bool::from(hook_account.transferring),
+            TransferHookError::NotTransferring
+        );
+        
+        // Seizures by the SSS permanent delegate may move blacklisted funds
+        let (permanent_delegate, _) = Pubkey::find_program_address(
+            &[b"permanent_delegate", ctx.accounts.config.key().as_ref()],
+            &sss_one::ID,
+        );
+        if ctx.accounts.owner.key() == permanent_delegate {
+            return Ok(());
+        }
+        
+        require!(
+            ctx.accounts.config.paused_operations & PauseFlags::TRANSFER == 0,
+            TransferHookError::TransfersPaused
+        );
+        require!(
+            !is_blacklisted(&ctx.accounts.source_blacklist),
+            TransferHookError::SourceBlacklisted
+        );
+        require!(
+            !is_blacklisted(&ctx.accounts.destination_blacklist),
+            TransferHookError::DestinationBlacklisted
+        );
+        
+        Ok(())
+    }
+
+    /// Route the interface's `Execute` discriminator to `transfer_hook`
+    pub fn fallback<'info>(
+        program_id: &Pubkey,
+        accounts: &'info [AccountInfo<'info>],
+        data: &[u8],
+    ) -> Result<()> {
+        match TransferHookInstruction::unpack(data)? {
+            TransferHookInstruction::Execute { amount } => {
+                __private::__global::transfer_hook(program_id, accounts, &amount.to_le_bytes())
+            }
+            _ => Err(ProgramError::InvalidInstructionData.into()),
+        }
+    }
+}